@@ -0,0 +1,107 @@
+//! Module for the itemized result of a paycheck calculation.
+//! A `PaycheckBreakdown` keeps every amount computed on the way from gross pay to net pay so callers can show a full paystub instead of a single net number.
+
+use crate::garnishments::GarnishmentLine;
+use crate::money::Money;
+use crate::withholdings::MedicareWithholding;
+use serde::Serialize;
+use std::fmt;
+
+/// A single named deduction line on a paystub
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DeductionLine {
+    pub name: String,
+    pub amount: Money,
+}
+
+/// Itemized paycheck, from gross pay down to net pay
+/// # Fields
+/// * `regular_pay` - pay for regular (non-overtime) hours
+/// * `overtime_pay` - pay for overtime hours
+/// * `gross_pay` - regular + overtime pay
+/// * `pretax_deductions` - each pre-tax deduction
+/// * `taxable_wages` - gross pay less pre-tax deductions, the wages subject to federal income tax
+/// * `fica_wages` - gross pay less the pre-tax deductions that are also exempt from Social Security and Medicare, the wages subject to Social Security and Medicare
+/// * `federal_withholding` - federal income tax withholding
+/// * `social_security` - Social Security withholding
+/// * `medicare` - Medicare withholding, split into base and Additional Medicare Tax
+/// * `posttax_deductions` - each post-tax deduction, garnishments at the amount withheld
+/// * `garnishments` - each garnishment order with the amount ordered, withheld and left in arrears, in the order they are withheld
+/// * `net_pay` - take-home pay
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PaycheckBreakdown {
+    pub regular_pay: Money,
+    pub overtime_pay: Money,
+    pub gross_pay: Money,
+    pub pretax_deductions: Vec<DeductionLine>,
+    pub taxable_wages: Money,
+    pub fica_wages: Money,
+    pub federal_withholding: Money,
+    pub social_security: Money,
+    pub medicare: MedicareWithholding,
+    pub posttax_deductions: Vec<DeductionLine>,
+    pub garnishments: Vec<GarnishmentLine>,
+    pub net_pay: Money,
+}
+
+impl PaycheckBreakdown {
+    /// Total of all pre-tax deductions
+    pub fn total_pretax_deductions(&self) -> Money {
+        self.pretax_deductions.iter().map(|line| line.amount).sum()
+    }
+
+    /// Total of federal, Social Security and Medicare withholdings
+    pub fn total_taxes(&self) -> Money {
+        self.federal_withholding + self.social_security + self.medicare.total()
+    }
+
+    /// Total of all post-tax deductions
+    pub fn total_posttax_deductions(&self) -> Money {
+        self.posttax_deductions.iter().map(|line| line.amount).sum()
+    }
+}
+
+impl fmt::Display for PaycheckBreakdown {
+    /// Formats the breakdown as a paystub with one line per amount
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line = |f: &mut fmt::Formatter<'_>, label: &str, amount: Money| {
+            writeln!(f, "{:<32}${:>12}", label, amount)
+        };
+
+        line(f, "Regular Pay", self.regular_pay)?;
+        line(f, "Overtime Pay", self.overtime_pay)?;
+        line(f, "Gross Pay", self.gross_pay)?;
+
+        writeln!(f, "\nPre-Tax Deductions")?;
+        for deduction in &self.pretax_deductions {
+            line(f, &format!("  {}", deduction.name), deduction.amount)?;
+        }
+        line(f, "Taxable Wages", self.taxable_wages)?;
+        line(f, "FICA Wages", self.fica_wages)?;
+
+        writeln!(f, "\nTaxes")?;
+        line(f, "  Federal Income Tax", self.federal_withholding)?;
+        line(f, "  Social Security", self.social_security)?;
+        line(f, "  Medicare", self.medicare.base)?;
+        line(f, "  Additional Medicare", self.medicare.additional)?;
+
+        writeln!(f, "\nPost-Tax Deductions")?;
+        for deduction in &self.posttax_deductions {
+            line(f, &format!("  {}", deduction.name), deduction.amount)?;
+        }
+        let arrears: Vec<&GarnishmentLine> = self
+            .garnishments
+            .iter()
+            .filter(|garnishment| !garnishment.arrears.is_zero())
+            .collect();
+        if !arrears.is_empty() {
+            writeln!(f, "\nGarnishment Arrears")?;
+            for garnishment in arrears {
+                line(f, &format!("  {}", garnishment.name), garnishment.arrears)?;
+            }
+        }
+
+        writeln!(f)?;
+        line(f, "Net Pay", self.net_pay)
+    }
+}
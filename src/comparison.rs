@@ -0,0 +1,420 @@
+//! Module for comparing employment scenarios side by side, e.g. to decide between job offers.
+//! A `Comparison` calculates every scenario and lines up the gross pay, each tax, the deductions, the net paycheck, the monthly net income, the monthly surplus over expenses and the annual total compensation with employer contributions, with the difference of each scenario from a chosen baseline scenario.
+//!
+//! Per paycheck amounts are only directly comparable between scenarios with the same pay frequency, so the monthly and annual rows are the ones to compare when the pay frequencies differ.
+
+use crate::EmploymentScenario;
+use crate::constants::PayFrequency;
+use crate::money::Money;
+use crate::report::{OutputFormat, REPORT_SCHEMA_VERSION, csv_field, markdown_cell};
+use anyhow::{Result, bail};
+use serde::Serialize;
+use std::fmt::{self, Write};
+use std::ops::Sub;
+
+/// Compared amounts of one scenario (or the difference between two scenarios)
+/// # Fields
+/// * `gross_pay` - gross pay per paycheck
+/// * `federal_income_tax` - federal income tax withholding per paycheck
+/// * `social_security` - Social Security withholding per paycheck
+/// * `medicare` - Medicare withholding per paycheck, including the Additional Medicare Tax
+/// * `pretax_deductions` - total pre-tax deductions per paycheck
+/// * `posttax_deductions` - total post-tax deductions per paycheck
+/// * `net_pay` - net pay per paycheck
+/// * `monthly_net_income` - net pay times the average paychecks per month
+/// * `monthly_expenses` - total monthly expenses
+/// * `monthly_surplus` - monthly net income less monthly expenses
+/// * `annual_employer_contributions` - the employer's 401(k) match, HSA contribution and paid premiums over a year
+/// * `annual_total_compensation` - gross wages of a year plus the employer contributions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ComparisonAmounts {
+    pub gross_pay: Money,
+    pub federal_income_tax: Money,
+    pub social_security: Money,
+    pub medicare: Money,
+    pub pretax_deductions: Money,
+    pub posttax_deductions: Money,
+    pub net_pay: Money,
+    pub monthly_net_income: Money,
+    pub monthly_expenses: Money,
+    pub monthly_surplus: Money,
+    pub annual_employer_contributions: Money,
+    pub annual_total_compensation: Money,
+}
+
+impl ComparisonAmounts {
+    /// Calculate the compared amounts of a scenario
+    pub fn new(scenario: &EmploymentScenario) -> Self {
+        let breakdown = scenario.calculate_paycheck_breakdown();
+        let (monthly_net_income, monthly_expenses, monthly_surplus) =
+            scenario.compare_monthly_expenses_to_monthly_income();
+        let total_compensation = scenario.total_compensation();
+        ComparisonAmounts {
+            gross_pay: breakdown.gross_pay,
+            federal_income_tax: breakdown.federal_withholding,
+            social_security: breakdown.social_security,
+            medicare: breakdown.medicare.total(),
+            pretax_deductions: breakdown.total_pretax_deductions(),
+            posttax_deductions: breakdown.total_posttax_deductions(),
+            net_pay: breakdown.net_pay,
+            monthly_net_income,
+            monthly_expenses,
+            monthly_surplus,
+            annual_employer_contributions: total_compensation.employer_contributions(),
+            annual_total_compensation: total_compensation.total,
+        }
+    }
+
+    /// One `(label, amount)` row for each compared amount, in table order
+    fn rows(&self) -> [(&'static str, Money); 12] {
+        [
+            ("Gross Pay", self.gross_pay),
+            ("Federal Income Tax", self.federal_income_tax),
+            ("Social Security", self.social_security),
+            ("Medicare", self.medicare),
+            ("Pre-Tax Deductions", self.pretax_deductions),
+            ("Post-Tax Deductions", self.posttax_deductions),
+            ("Net Pay", self.net_pay),
+            ("Monthly Net Income", self.monthly_net_income),
+            ("Monthly Expenses", self.monthly_expenses),
+            ("Monthly Surplus", self.monthly_surplus),
+            (
+                "Annual Employer Contributions",
+                self.annual_employer_contributions,
+            ),
+            ("Annual Total Compensation", self.annual_total_compensation),
+        ]
+    }
+}
+
+impl Sub for ComparisonAmounts {
+    type Output = ComparisonAmounts;
+
+    fn sub(self, other: ComparisonAmounts) -> ComparisonAmounts {
+        ComparisonAmounts {
+            gross_pay: self.gross_pay - other.gross_pay,
+            federal_income_tax: self.federal_income_tax - other.federal_income_tax,
+            social_security: self.social_security - other.social_security,
+            medicare: self.medicare - other.medicare,
+            pretax_deductions: self.pretax_deductions - other.pretax_deductions,
+            posttax_deductions: self.posttax_deductions - other.posttax_deductions,
+            net_pay: self.net_pay - other.net_pay,
+            monthly_net_income: self.monthly_net_income - other.monthly_net_income,
+            monthly_expenses: self.monthly_expenses - other.monthly_expenses,
+            monthly_surplus: self.monthly_surplus - other.monthly_surplus,
+            annual_employer_contributions: self.annual_employer_contributions
+                - other.annual_employer_contributions,
+            annual_total_compensation: self.annual_total_compensation
+                - other.annual_total_compensation,
+        }
+    }
+}
+
+/// One compared scenario
+/// # Fields
+/// * `name` - name of the scenario (e.g. its file name)
+/// * `pay_frequency` - how often the scenario's paychecks are issued
+/// * `amounts` - the compared amounts (flattened into the scenario's JSON object)
+/// * `delta` - the amounts less the baseline scenario's amounts (all zero for the baseline itself)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ComparedScenario {
+    pub name: String,
+    pub pay_frequency: PayFrequency,
+    #[serde(flatten)]
+    pub amounts: ComparisonAmounts,
+    pub delta: ComparisonAmounts,
+}
+
+/// Side-by-side comparison of several employment scenarios against a baseline scenario
+/// # Fields
+/// * `schema_version` - version of the JSON schema (`REPORT_SCHEMA_VERSION`)
+/// * `baseline` - name of the baseline scenario
+/// * `scenarios` - the compared scenarios, in the order given
+/// # Example
+/// ```
+/// use paycheck_utils::*;
+///
+/// let offer = |rate| EmploymentScenario::new(
+///     Money::new(rate, 0),
+///     40.0,
+///     FilingStatus::Single,
+///     PreTaxDeductions::default(),
+///     PostTaxDeductions::default(),
+///     Expenses::new(vec![Expense::Housing(Some(Money::new(1500, 0)))]),
+/// );
+/// let comparison = Comparison::new(
+///     &[("current".to_string(), offer(25)), ("offer".to_string(), offer(30))],
+///     "current",
+/// ).unwrap();
+///
+/// let offer = &comparison.scenarios[1];
+/// assert_eq!(offer.delta.gross_pay, Money::new(400, 0)); // $5/hour more for 80 hours
+/// assert!(offer.delta.net_pay > Money::ZERO);
+/// println!("{}", comparison.render(OutputFormat::Text)); // side-by-side table
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Comparison {
+    pub schema_version: u32,
+    pub baseline: String,
+    pub scenarios: Vec<ComparedScenario>,
+}
+
+impl Comparison {
+    /// Calculate and compare named scenarios against the scenario named `baseline`
+    /// # Errors
+    /// * when no scenario is named `baseline`
+    /// * when two scenarios have the same name
+    pub fn new(scenarios: &[(String, EmploymentScenario)], baseline: &str) -> Result<Self> {
+        for (index, (name, _)) in scenarios.iter().enumerate() {
+            if scenarios[..index].iter().any(|(other, _)| other == name) {
+                bail!("more than one scenario is named '{name}'");
+            }
+        }
+        let Some((_, baseline_scenario)) = scenarios.iter().find(|(name, _)| name == baseline)
+        else {
+            bail!(
+                "no scenario named '{baseline}' to compare against (scenarios: {})",
+                scenarios
+                    .iter()
+                    .map(|(name, _)| name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        };
+        let baseline_amounts = ComparisonAmounts::new(baseline_scenario);
+
+        Ok(Comparison {
+            schema_version: REPORT_SCHEMA_VERSION,
+            baseline: baseline.to_string(),
+            scenarios: scenarios
+                .iter()
+                .map(|(name, scenario)| {
+                    let amounts = ComparisonAmounts::new(scenario);
+                    ComparedScenario {
+                        name: name.clone(),
+                        pay_frequency: scenario.pay_frequency,
+                        amounts,
+                        delta: amounts - baseline_amounts,
+                    }
+                })
+                .collect(),
+        })
+    }
+
+    /// Render the comparison in the given format
+    pub fn render(&self, format: OutputFormat) -> String {
+        match format {
+            OutputFormat::Text => self.to_string(),
+            OutputFormat::Json => {
+                serde_json::to_string_pretty(self).expect("comparison serializes to JSON")
+            }
+            OutputFormat::Csv => self.to_csv(),
+            OutputFormat::Markdown => self.to_markdown(),
+        }
+    }
+
+    /// Column headers: the label column, one column per scenario, then one delta column per scenario other than the baseline
+    fn headers(&self) -> Vec<String> {
+        let mut headers = vec![String::new()];
+        headers.extend(self.scenarios.iter().map(|scenario| scenario.name.clone()));
+        headers.extend(
+            self.compared_to_baseline()
+                .map(|scenario| format!("{} vs {}", scenario.name, self.baseline)),
+        );
+        headers
+    }
+
+    /// Table rows in the same column order as `headers`, with `+` signs on the deltas
+    fn rows(&self) -> Vec<Vec<String>> {
+        let mut pay_frequency = vec!["Pay Frequency".to_string()];
+        pay_frequency.extend(
+            self.scenarios
+                .iter()
+                .map(|scenario| pay_frequency_name(scenario.pay_frequency).to_string()),
+        );
+        pay_frequency.extend(self.compared_to_baseline().map(|_| String::new()));
+
+        let mut rows = vec![pay_frequency];
+        for (index, (label, _)) in ComparisonAmounts::default().rows().iter().enumerate() {
+            let mut row = vec![label.to_string()];
+            row.extend(
+                self.scenarios
+                    .iter()
+                    .map(|scenario| scenario.amounts.rows()[index].1.to_string()),
+            );
+            row.extend(
+                self.compared_to_baseline()
+                    .map(|scenario| format!("{:+}", scenario.delta.rows()[index].1)),
+            );
+            rows.push(row);
+        }
+        rows
+    }
+
+    /// Scenarios other than the baseline
+    fn compared_to_baseline(&self) -> impl Iterator<Item = &ComparedScenario> {
+        self.scenarios
+            .iter()
+            .filter(|scenario| scenario.name != self.baseline)
+    }
+
+    /// CSV with the same columns as the text table
+    fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        for row in std::iter::once(self.headers()).chain(self.rows()) {
+            let fields: Vec<String> = row.iter().map(|field| csv_field(field)).collect();
+            let _ = writeln!(csv, "{}", fields.join(","));
+        }
+        csv
+    }
+
+    /// Markdown table with the same columns as the text table
+    fn to_markdown(&self) -> String {
+        let headers = self.headers();
+        let mut markdown = format!(
+            "| {} |\n| --- |{}\n",
+            headers
+                .iter()
+                .map(|header| markdown_cell(header))
+                .collect::<Vec<_>>()
+                .join(" | "),
+            " ---: |".repeat(headers.len() - 1)
+        );
+        for row in self.rows() {
+            let _ = writeln!(
+                markdown,
+                "| {} |",
+                row.iter()
+                    .map(|cell| markdown_cell(cell))
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            );
+        }
+        markdown
+    }
+}
+
+impl fmt::Display for Comparison {
+    /// Formats the comparison as a table with one row per compared amount and one column per scenario, followed by the delta columns
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let headers = self.headers();
+        let rows = self.rows();
+        let widths: Vec<usize> = (0..headers.len())
+            .map(|column| {
+                std::iter::once(&headers)
+                    .chain(&rows)
+                    .map(|row| row[column].len())
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        for row in std::iter::once(&headers).chain(&rows) {
+            let mut line = format!("{:<width$}", row[0], width = widths[0]);
+            for (cell, width) in row.iter().zip(&widths).skip(1) {
+                let _ = write!(line, "  {cell:>width$}");
+            }
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+/// Name of a pay frequency as accepted by the `--pay-frequency` flag
+fn pay_frequency_name(pay_frequency: PayFrequency) -> &'static str {
+    match pay_frequency {
+        PayFrequency::Weekly => "weekly",
+        PayFrequency::BiWeekly => "bi-weekly",
+        PayFrequency::SemiMonthly => "semi-monthly",
+        PayFrequency::Monthly => "monthly",
+    }
+}
+
+// UNIT TESTS FOR COMPARISON MODULE
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    fn scenarios() -> Vec<(String, EmploymentScenario)> {
+        let offer = |rate, pretax| {
+            EmploymentScenario::new(
+                Money::new(rate, 0),
+                40.0,
+                FilingStatus::Single,
+                PreTaxDeductions::new(vec![PreTaxDeduction::Medical(Some(
+                    Money::new(pretax, 0).into(),
+                ))]),
+                PostTaxDeductions::default(),
+                Expenses::new(vec![Expense::Housing(Some(Money::new(1500, 0)))]),
+            )
+        };
+        vec![
+            ("current".to_string(), offer(25, 100)),
+            ("offer-a".to_string(), offer(28, 250)),
+            ("offer-b".to_string(), offer(30, 0)),
+        ]
+    }
+
+    #[test]
+    fn test_comparison_deltas() {
+        let scenarios = scenarios();
+        let comparison = Comparison::new(&scenarios, "current").unwrap();
+        assert_eq!(comparison.scenarios.len(), 3);
+        assert_eq!(comparison.scenarios[0].delta, ComparisonAmounts::default());
+
+        let offer_a = &comparison.scenarios[1];
+        assert_eq!(offer_a.delta.gross_pay, Money::new(240, 0));
+        assert_eq!(offer_a.delta.pretax_deductions, Money::new(150, 0));
+        assert_eq!(
+            offer_a.delta.net_pay,
+            offer_a.amounts.net_pay - comparison.scenarios[0].amounts.net_pay
+        );
+        assert_eq!(offer_a.delta.monthly_expenses, Money::ZERO);
+
+        // against another baseline
+        let comparison = Comparison::new(&scenarios, "offer-b").unwrap();
+        assert_eq!(comparison.scenarios[0].delta.gross_pay, Money::new(-400, 0));
+        assert!(Comparison::new(&scenarios, "offer-c").is_err());
+
+        let duplicate = vec![scenarios[0].clone(), scenarios[0].clone()];
+        assert!(Comparison::new(&duplicate, "current").is_err());
+    }
+
+    #[test]
+    fn test_comparison_render() {
+        let comparison = Comparison::new(&scenarios(), "current").unwrap();
+
+        let text = comparison.render(OutputFormat::Text);
+        let header = text.lines().next().unwrap();
+        assert!(header.contains("offer-a vs current"), "{header}");
+        assert!(text.contains("+240.00"), "{text}");
+
+        let csv = comparison.render(OutputFormat::Csv);
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some(",current,offer-a,offer-b,offer-a vs current,offer-b vs current")
+        );
+        assert_eq!(
+            lines.next(),
+            Some("Pay Frequency,bi-weekly,bi-weekly,bi-weekly,,")
+        );
+        assert_eq!(
+            lines.next(),
+            Some("Gross Pay,2000.00,2240.00,2400.00,+240.00,+400.00")
+        );
+
+        let markdown = comparison.render(OutputFormat::Markdown);
+        assert!(
+            markdown.contains("| Gross Pay | 2000.00 | 2240.00 | 2400.00 | +240.00 | +400.00 |")
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&comparison.render(OutputFormat::Json)).unwrap();
+        assert_eq!(json["baseline"], "current");
+        assert_eq!(json["scenarios"][2]["gross_pay"], 2400.0);
+        assert_eq!(json["scenarios"][2]["delta"]["gross_pay"], 400.0);
+    }
+}
@@ -1,12 +1,66 @@
 //! Module containing utility constants and enums for payroll calculations.
 //! Tax related constants are based on IRS guidelines for the year 2026.
-//! Focus is given to single filer status, with future implementation plans for other filing statuses.
+//! Standard deductions and tax brackets are defined for all four filing statuses.
+//! Dollar amounts are exact `Money` values; rates and hours are `f32`.
 
-/// 2 week pay periods
-pub const PAY_PERIOD: f32 = 2.0;
+use crate::money::Money;
+use serde::{Deserialize, Serialize};
 
-/// 26 bi-weekly pay periods in a year
-pub const PAY_PERIODS_PER_YEAR: f32 = 26.0;
+/// 52 weeks in a year
+pub const WEEKS_PER_YEAR: f32 = 52.0;
+
+/// 12 months in a year
+pub const MONTHS_PER_YEAR: f32 = 12.0;
+
+/// How often paychecks are issued
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PayFrequency {
+    Weekly,
+    #[default]
+    BiWeekly,
+    SemiMonthly,
+    Monthly,
+}
+
+impl PayFrequency {
+    /// Number of pay periods in a year (52 weekly, 26 bi-weekly, 24 semi-monthly, 12 monthly)
+    pub fn periods_per_year(&self) -> f32 {
+        match self {
+            PayFrequency::Weekly => 52.0,
+            PayFrequency::BiWeekly => 26.0,
+            PayFrequency::SemiMonthly => 24.0,
+            PayFrequency::Monthly => 12.0,
+        }
+    }
+
+    /// Number of weeks in a pay period (52 weeks / periods per year, e.g. 2 for bi-weekly and about 4.33 for monthly)
+    pub fn weeks_per_period(&self) -> f32 {
+        WEEKS_PER_YEAR / self.periods_per_year()
+    }
+
+    /// Average number of paychecks in a month (periods per year / 12, e.g. 26/12 for bi-weekly)
+    pub fn paychecks_per_month(&self) -> f32 {
+        self.periods_per_year() / MONTHS_PER_YEAR
+    }
+}
+
+impl std::str::FromStr for PayFrequency {
+    type Err = String;
+
+    /// Parses "weekly", "biweekly", "semimonthly" or "monthly" (case insensitive, with or without a hyphen)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace('-', "").as_str() {
+            "weekly" => Ok(PayFrequency::Weekly),
+            "biweekly" => Ok(PayFrequency::BiWeekly),
+            "semimonthly" => Ok(PayFrequency::SemiMonthly),
+            "monthly" => Ok(PayFrequency::Monthly),
+            other => Err(format!(
+                "unknown pay frequency '{other}' (expected weekly, biweekly, semimonthly or monthly)"
+            )),
+        }
+    }
+}
 
 /// standard 40 hour full-time hours per week
 pub const STANDARD_HOURS_PER_WEEK: f32 = 40.0;
@@ -16,39 +70,125 @@ pub const OVERTIME_MULTIPLIER: f32 = 1.5;
 // pub const PAID_TIME_OFF_WEEKS_PER_YEAR: f32 = 3.0; // possible future integration of overtime not possible during PTO
 
 /// 2026 filing statuses
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum FilingStatus {
     #[default]
     Single,
-    MarriedFilingJointly,  // for future implementation
-    MarriedFilingSeparate, // for future implementation
-    HeadOfHousehold,       // for future implementation
+    MarriedFilingJointly,
+    MarriedFilingSeparate,
+    HeadOfHousehold,
 }
 
 impl FilingStatus {
+    /// The single filing status, same as `Default::default()`
+    #[allow(clippy::should_implement_trait)]
     pub fn default() -> Self {
-        FilingStatus::Single
+        <FilingStatus as Default>::default()
+    }
+}
+
+impl std::str::FromStr for FilingStatus {
+    type Err = String;
+
+    /// Parses "single", "married-jointly" (or "mfj"), "married-separately" (or "mfs") or "head-of-household" (or "hoh"), case insensitive
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s
+            .trim()
+            .to_lowercase()
+            .replace(['-', '_', ' '], "")
+            .as_str()
+        {
+            "single" => Ok(FilingStatus::Single),
+            "marriedjointly" | "marriedfilingjointly" | "mfj" => {
+                Ok(FilingStatus::MarriedFilingJointly)
+            }
+            "marriedseparately" | "marriedfilingseparately" | "marriedfilingseparate" | "mfs" => {
+                Ok(FilingStatus::MarriedFilingSeparate)
+            }
+            "headofhousehold" | "hoh" => Ok(FilingStatus::HeadOfHousehold),
+            _ => Err(format!(
+                "unknown filing status '{s}' (expected single, married-jointly, married-separately or head-of-household)"
+            )),
+        }
     }
 }
 
 /// 2026 standard deduction for single filer: $16,100 (source: irs.gov)
-pub const SINGLE_DEDUCTION: f32 = 16100.00;
+pub const SINGLE_DEDUCTION: Money = Money::new(16_100, 0);
 
 /// 2026 standard deduction for head of household filer: $24,150 (source: irs.gov)
-pub const HEAD_OF_HOUSEHOLD_DEDUCTION: f32 = 24150.00; // for future implementation
+pub const HEAD_OF_HOUSEHOLD_DEDUCTION: Money = Money::new(24_150, 0);
 
 /// 2026 standard deduction for married filing jointly filer: $32,200 (source: irs.gov)
-pub const MARRIED_FILING_JOINTLY_DEDUCTION: f32 = 32200.00; // for future implementation
+pub const MARRIED_FILING_JOINTLY_DEDUCTION: Money = Money::new(32_200, 0);
 
 /// 2026 standard deduction for married filing separately filer: $16,100 (source: irs.gov)
-pub const MARRIED_FILING_SEPERATE_DEDUCTION: f32 = 16100.00; // for future implementation
+pub const MARRIED_FILING_SEPERATE_DEDUCTION: Money = Money::new(16_100, 0);
+
+/// 2026 Publication 15-T percentage method adjustment (Worksheet 1A line 1g) for married filing jointly when the Form W-4 Step 2 box is not checked: $12,900 (source: irs.gov)
+pub const MARRIED_FILING_JOINTLY_PERCENTAGE_METHOD_ADJUSTMENT: Money = Money::new(12_900, 0);
+
+/// 2026 Publication 15-T percentage method adjustment (Worksheet 1A line 1g) for all other filing statuses when the Form W-4 Step 2 box is not checked: $8,600 (source: irs.gov)
+pub const PERCENTAGE_METHOD_ADJUSTMENT: Money = Money::new(8_600, 0);
 
 /// 2026 Social Security tax rate: 6.2% (source: irs.gov)
 pub const SOCIAL_SECURITY_RATE: f32 = 0.062;
 
+/// 2026 Social Security wage base (maximum wages subject to Social Security tax): $184,500 (source: ssa.gov)
+pub const SOCIAL_SECURITY_WAGE_BASE: Money = Money::new(184_500, 0);
+
 /// 2026 Medicare tax rate: 1.45% (source: irs.gov)
 pub const MEDICARE_RATE: f32 = 0.0145;
 
+/// 2026 Additional Medicare Tax rate: 0.9% (source: irs.gov)
+pub const ADDITIONAL_MEDICARE_RATE: f32 = 0.009;
+
+/// Wages in a calendar year over which the employer must withhold Additional Medicare Tax: $200,000 (source: irs.gov)
+pub const ADDITIONAL_MEDICARE_THRESHOLD: Money = Money::new(200_000, 0);
+
+/// 2026 elective deferral limit (IRC 402(g)) for traditional and Roth 401(k), 403(b) and governmental 457(b) contributions combined: $24,500 (source: irs.gov)
+pub const ELECTIVE_DEFERRAL_LIMIT: Money = Money::new(24_500, 0);
+
+/// 2026 catch-up contribution limit for employees age 50 or older: $8,000 (source: irs.gov)
+pub const ELECTIVE_DEFERRAL_CATCH_UP: Money = Money::new(8_000, 0);
+
+/// 2026 catch-up contribution limit for employees age 60 through 63, in place of the age 50 catch-up: $11,250 (source: irs.gov)
+pub const ELECTIVE_DEFERRAL_AGE_60_TO_63_CATCH_UP: Money = Money::new(11_250, 0);
+
+/// 2026 HSA contribution limit for self-only coverage: $4,400 (source: irs.gov)
+pub const HSA_SELF_ONLY_LIMIT: Money = Money::new(4_400, 0);
+
+/// 2026 HSA contribution limit for family coverage: $8,750 (source: irs.gov)
+pub const HSA_FAMILY_LIMIT: Money = Money::new(8_750, 0);
+
+/// HSA catch-up contribution for account holders age 55 or older: $1,000 (source: irs.gov)
+pub const HSA_CATCH_UP: Money = Money::new(1_000, 0);
+
+/// 2026 health FSA salary reduction limit: $3,400 (source: irs.gov)
+pub const HEALTH_FSA_LIMIT: Money = Money::new(3_400, 0);
+
+/// Federal minimum wage under the Fair Labor Standards Act: $7.25 per hour (source: dol.gov)
+pub const FEDERAL_MINIMUM_WAGE: Money = Money::new(7, 25);
+
+/// Consumer Credit Protection Act limit on ordinary (creditor) garnishments: 25% of disposable earnings (source: dol.gov)
+pub const CCPA_CREDITOR_GARNISHMENT_RATE: f32 = 0.25;
+
+/// Weekly disposable earnings the CCPA protects from ordinary garnishments: 30 times the federal minimum wage (source: dol.gov)
+pub const CCPA_MINIMUM_WAGE_MULTIPLE: f32 = 30.0;
+
+/// CCPA limit on support garnishments for an employee supporting a second family (another spouse or child): 50% of disposable earnings (source: dol.gov)
+pub const CCPA_SUPPORT_SECOND_FAMILY_RATE: f32 = 0.50;
+
+/// CCPA limit on support garnishments for an employee not supporting a second family: 60% of disposable earnings (source: dol.gov)
+pub const CCPA_SUPPORT_RATE: f32 = 0.60;
+
+/// Addition to the CCPA support limit when support is more than 12 weeks in arrears: 5% of disposable earnings (source: dol.gov)
+pub const CCPA_SUPPORT_ARREARS_RATE: f32 = 0.05;
+
+/// Limit on administrative wage garnishment for defaulted federal student loans: 15% of disposable pay (source: studentaid.gov)
+pub const STUDENT_LOAN_GARNISHMENT_RATE: f32 = 0.15;
+
 /// 2026 tax bracket 1 rate: 10% (source: irs.gov)
 pub const TAX_BRACKET_1_RATE: f32 = 0.10; // 10%
 
@@ -71,66 +211,145 @@ pub const TAX_BRACKET_6_RATE: f32 = 0.35; // 35%
 pub const TAX_BRACKET_7_RATE: f32 = 0.37; // 37%
 
 /// 2026 single filer tax bracket 1 upper limit threshold: $12,400 (source: irs.gov)
-pub const SINGLE_BRACKET_1_THRESHOLD: f32 = 12400.00;
+pub const SINGLE_BRACKET_1_THRESHOLD: Money = Money::new(12_400, 0);
 
 /// 2026 single filer tax bracket 2 upper limit threshold: $50,400 (source: irs.gov)
-pub const SINGLE_BRACKET_2_THRESHOLD: f32 = 50400.00;
+pub const SINGLE_BRACKET_2_THRESHOLD: Money = Money::new(50_400, 0);
 
 /// 2026 single filer tax bracket 3 upper limit threshold: $105,700 (source: irs.gov)
-pub const SINGLE_BRACKET_3_THRESHOLD: f32 = 105700.00;
+pub const SINGLE_BRACKET_3_THRESHOLD: Money = Money::new(105_700, 0);
 
 /// 2026 single filer tax bracket 4 upper limit threshold: $201,775 (source: irs.gov)
-pub const SINGLE_BRACKET_4_THRESHOLD: f32 = 201775.00;
+pub const SINGLE_BRACKET_4_THRESHOLD: Money = Money::new(201_775, 0);
 
 /// 2026 single filer tax bracket 5 upper limit threshold: $256,225 (source: irs.gov)
-pub const SINGLE_BRACKET_5_THRESHOLD: f32 = 256225.00;
+pub const SINGLE_BRACKET_5_THRESHOLD: Money = Money::new(256_225, 0);
 
 /// 2026 single filer tax bracket 6 upper limit threshold: $640,600 (source: irs.gov)
-pub const SINGLE_BRACKET_6_THRESHOLD: f32 = 640600.00;
+pub const SINGLE_BRACKET_6_THRESHOLD: Money = Money::new(640_600, 0);
 
 /// 2026 single filer tax bracket 2 base tax amount: $1,240.00 (source: irs.gov)
-pub const SINGLE_BRACKET_2_BASE_TAX: f32 = 1240.00;
+pub const SINGLE_BRACKET_2_BASE_TAX: Money = Money::new(1_240, 0);
 
 /// 2026 single filer tax bracket 3 base tax amount: $5,800.00 (source: irs.gov)
-pub const SINGLE_BRACKET_3_BASE_TAX: f32 = 5800.00;
+pub const SINGLE_BRACKET_3_BASE_TAX: Money = Money::new(5_800, 0);
 
-/// 2026 single filer tax bracket 4 base tax amount: $17,996.00 (source: irs.gov)
-pub const SINGLE_BRACKET_4_BASE_TAX: f32 = 17996.00;
+/// 2026 single filer tax bracket 4 base tax amount: $17,966.00 (source: irs.gov)
+pub const SINGLE_BRACKET_4_BASE_TAX: Money = Money::new(17_966, 0);
 
 /// 2026 single filer tax bracket 5 base tax amount: $41,024.00 (source: irs.gov)
-pub const SINGLE_BRACKET_5_BASE_TAX: f32 = 41024.00;
+pub const SINGLE_BRACKET_5_BASE_TAX: Money = Money::new(41_024, 0);
 
 /// 2026 single filer tax bracket 6 base tax amount: $58,448.00 (source: irs.gov)
-pub const SINGLE_BRACKET_6_BASE_TAX: f32 = 58448.00;
+pub const SINGLE_BRACKET_6_BASE_TAX: Money = Money::new(58_448, 0);
 
 /// 2026 single filer tax bracket 7 base tax amount: $192,979.25 (source: irs.gov)
-pub const SINGLE_BRACKET_7_BASE_TAX: f32 = 192979.25;
-
-// FUTURE IMPLEMENTATION OF OTHER FILING STATUS TAX BRACKETS
-
-// // 2026 tax brackets thresholds for married filing jointly filers
-// pub const MARRIED_JOINTLY_BRACKET_1_THRESHOLD: f32 = 24800.00;
-// pub const MARRIED_JOINTLY_BRACKET_2_THRESHOLD: f32 = 100800.00;
-// pub const MARRIED_JOINTLY_BRACKET_3_THRESHOLD: f32 = 211400.00;
-// pub const MARRIED_JOINTLY_BRACKET_4_THRESHOLD: f32 = 403550.00;
-// pub const MARRIED_JOINTLY_BRACKET_5_THRESHOLD: f32 = 512450.00;
-// pub const MARRIED_JOINTLY_BRACKET_6_THRESHOLD: f32 = 768700.00;
-// pub const MARRIED_JOINTLY_BRACKET_7_THRESHOLD: f32 = f32::MAX;
-
-// // 2026 tax brackets thresholds for married filing separately filers
-// pub const MARRIED_SEPARATELY_BRACKET_1_THRESHOLD: f32 = 12400.00;
-// pub const MARRIED_SEPARATELY_BRACKET_2_THRESHOLD: f32 = 50400.00;
-// pub const MARRIED_SEPARATELY_BRACKET_3_THRESHOLD: f32 = 105700.00;
-// pub const MARRIED_SEPARATELY_BRACKET_4_THRESHOLD: f32 = 201775.00;
-// pub const MARRIED_SEPARATELY_BRACKET_5_THRESHOLD: f32 = 256225.00;
-// pub const MARRIED_SEPARATELY_BRACKET_6_THRESHOLD: f32 = 384350.00;
-// pub const MARRIED_SEPARATELY_BRACKET_7_THRESHOLD: f32 = f32::MAX;
-
-// // 2026 tax brackets thresholds for head of household filers
-// pub const HEAD_OF_HOUSEHOLD_BRACKET_1_THRESHOLD: f32 = 17700.00;
-// pub const HEAD_OF_HOUSEHOLD_BRACKET_2_THRESHOLD: f32 = 67450.00;
-// pub const HEAD_OF_HOUSEHOLD_BRACKET_3_THRESHOLD: f32 = 105700.00;
-// pub const HEAD_OF_HOUSEHOLD_BRACKET_4_THRESHOLD: f32 = 201750.00;
-// pub const HEAD_OF_HOUSEHOLD_BRACKET_5_THRESHOLD: f32 = 256200.00;
-// pub const HEAD_OF_HOUSEHOLD_BRACKET_6_THRESHOLD: f32 = 640600.00;
-// pub const HEAD_OF_HOUSEHOLD_BRACKET_7_THRESHOLD: f32 = f32::MAX;
+pub const SINGLE_BRACKET_7_BASE_TAX: Money = Money::new(192_979, 25);
+
+/// 2026 married filing jointly tax bracket 1 upper limit threshold: $24,800 (source: irs.gov)
+pub const MARRIED_JOINTLY_BRACKET_1_THRESHOLD: Money = Money::new(24_800, 0);
+
+/// 2026 married filing jointly tax bracket 2 upper limit threshold: $100,800 (source: irs.gov)
+pub const MARRIED_JOINTLY_BRACKET_2_THRESHOLD: Money = Money::new(100_800, 0);
+
+/// 2026 married filing jointly tax bracket 3 upper limit threshold: $211,400 (source: irs.gov)
+pub const MARRIED_JOINTLY_BRACKET_3_THRESHOLD: Money = Money::new(211_400, 0);
+
+/// 2026 married filing jointly tax bracket 4 upper limit threshold: $403,550 (source: irs.gov)
+pub const MARRIED_JOINTLY_BRACKET_4_THRESHOLD: Money = Money::new(403_550, 0);
+
+/// 2026 married filing jointly tax bracket 5 upper limit threshold: $512,450 (source: irs.gov)
+pub const MARRIED_JOINTLY_BRACKET_5_THRESHOLD: Money = Money::new(512_450, 0);
+
+/// 2026 married filing jointly tax bracket 6 upper limit threshold: $768,700 (source: irs.gov)
+pub const MARRIED_JOINTLY_BRACKET_6_THRESHOLD: Money = Money::new(768_700, 0);
+
+/// 2026 married filing jointly tax bracket 2 base tax amount: $2,480.00 (source: irs.gov)
+pub const MARRIED_JOINTLY_BRACKET_2_BASE_TAX: Money = Money::new(2_480, 0);
+
+/// 2026 married filing jointly tax bracket 3 base tax amount: $11,600.00 (source: irs.gov)
+pub const MARRIED_JOINTLY_BRACKET_3_BASE_TAX: Money = Money::new(11_600, 0);
+
+/// 2026 married filing jointly tax bracket 4 base tax amount: $35,932.00 (source: irs.gov)
+pub const MARRIED_JOINTLY_BRACKET_4_BASE_TAX: Money = Money::new(35_932, 0);
+
+/// 2026 married filing jointly tax bracket 5 base tax amount: $82,048.00 (source: irs.gov)
+pub const MARRIED_JOINTLY_BRACKET_5_BASE_TAX: Money = Money::new(82_048, 0);
+
+/// 2026 married filing jointly tax bracket 6 base tax amount: $116,896.00 (source: irs.gov)
+pub const MARRIED_JOINTLY_BRACKET_6_BASE_TAX: Money = Money::new(116_896, 0);
+
+/// 2026 married filing jointly tax bracket 7 base tax amount: $206,583.50 (source: irs.gov)
+pub const MARRIED_JOINTLY_BRACKET_7_BASE_TAX: Money = Money::new(206_583, 50);
+
+/// 2026 married filing separately tax bracket 1 upper limit threshold: $12,400 (source: irs.gov)
+pub const MARRIED_SEPARATELY_BRACKET_1_THRESHOLD: Money = Money::new(12_400, 0);
+
+/// 2026 married filing separately tax bracket 2 upper limit threshold: $50,400 (source: irs.gov)
+pub const MARRIED_SEPARATELY_BRACKET_2_THRESHOLD: Money = Money::new(50_400, 0);
+
+/// 2026 married filing separately tax bracket 3 upper limit threshold: $105,700 (source: irs.gov)
+pub const MARRIED_SEPARATELY_BRACKET_3_THRESHOLD: Money = Money::new(105_700, 0);
+
+/// 2026 married filing separately tax bracket 4 upper limit threshold: $201,775 (source: irs.gov)
+pub const MARRIED_SEPARATELY_BRACKET_4_THRESHOLD: Money = Money::new(201_775, 0);
+
+/// 2026 married filing separately tax bracket 5 upper limit threshold: $256,225 (source: irs.gov)
+pub const MARRIED_SEPARATELY_BRACKET_5_THRESHOLD: Money = Money::new(256_225, 0);
+
+/// 2026 married filing separately tax bracket 6 upper limit threshold: $384,350 (source: irs.gov)
+pub const MARRIED_SEPARATELY_BRACKET_6_THRESHOLD: Money = Money::new(384_350, 0);
+
+/// 2026 married filing separately tax bracket 2 base tax amount: $1,240.00 (source: irs.gov)
+pub const MARRIED_SEPARATELY_BRACKET_2_BASE_TAX: Money = Money::new(1_240, 0);
+
+/// 2026 married filing separately tax bracket 3 base tax amount: $5,800.00 (source: irs.gov)
+pub const MARRIED_SEPARATELY_BRACKET_3_BASE_TAX: Money = Money::new(5_800, 0);
+
+/// 2026 married filing separately tax bracket 4 base tax amount: $17,966.00 (source: irs.gov)
+pub const MARRIED_SEPARATELY_BRACKET_4_BASE_TAX: Money = Money::new(17_966, 0);
+
+/// 2026 married filing separately tax bracket 5 base tax amount: $41,024.00 (source: irs.gov)
+pub const MARRIED_SEPARATELY_BRACKET_5_BASE_TAX: Money = Money::new(41_024, 0);
+
+/// 2026 married filing separately tax bracket 6 base tax amount: $58,448.00 (source: irs.gov)
+pub const MARRIED_SEPARATELY_BRACKET_6_BASE_TAX: Money = Money::new(58_448, 0);
+
+/// 2026 married filing separately tax bracket 7 base tax amount: $103,291.75 (source: irs.gov)
+pub const MARRIED_SEPARATELY_BRACKET_7_BASE_TAX: Money = Money::new(103_291, 75);
+
+/// 2026 head of household tax bracket 1 upper limit threshold: $17,700 (source: irs.gov)
+pub const HEAD_OF_HOUSEHOLD_BRACKET_1_THRESHOLD: Money = Money::new(17_700, 0);
+
+/// 2026 head of household tax bracket 2 upper limit threshold: $67,450 (source: irs.gov)
+pub const HEAD_OF_HOUSEHOLD_BRACKET_2_THRESHOLD: Money = Money::new(67_450, 0);
+
+/// 2026 head of household tax bracket 3 upper limit threshold: $105,700 (source: irs.gov)
+pub const HEAD_OF_HOUSEHOLD_BRACKET_3_THRESHOLD: Money = Money::new(105_700, 0);
+
+/// 2026 head of household tax bracket 4 upper limit threshold: $201,750 (source: irs.gov)
+pub const HEAD_OF_HOUSEHOLD_BRACKET_4_THRESHOLD: Money = Money::new(201_750, 0);
+
+/// 2026 head of household tax bracket 5 upper limit threshold: $256,200 (source: irs.gov)
+pub const HEAD_OF_HOUSEHOLD_BRACKET_5_THRESHOLD: Money = Money::new(256_200, 0);
+
+/// 2026 head of household tax bracket 6 upper limit threshold: $640,600 (source: irs.gov)
+pub const HEAD_OF_HOUSEHOLD_BRACKET_6_THRESHOLD: Money = Money::new(640_600, 0);
+
+/// 2026 head of household tax bracket 2 base tax amount: $1,770.00 (source: irs.gov)
+pub const HEAD_OF_HOUSEHOLD_BRACKET_2_BASE_TAX: Money = Money::new(1_770, 0);
+
+/// 2026 head of household tax bracket 3 base tax amount: $7,740.00 (source: irs.gov)
+pub const HEAD_OF_HOUSEHOLD_BRACKET_3_BASE_TAX: Money = Money::new(7_740, 0);
+
+/// 2026 head of household tax bracket 4 base tax amount: $16,155.00 (source: irs.gov)
+pub const HEAD_OF_HOUSEHOLD_BRACKET_4_BASE_TAX: Money = Money::new(16_155, 0);
+
+/// 2026 head of household tax bracket 5 base tax amount: $39,207.00 (source: irs.gov)
+pub const HEAD_OF_HOUSEHOLD_BRACKET_5_BASE_TAX: Money = Money::new(39_207, 0);
+
+/// 2026 head of household tax bracket 6 base tax amount: $56,631.00 (source: irs.gov)
+pub const HEAD_OF_HOUSEHOLD_BRACKET_6_BASE_TAX: Money = Money::new(56_631, 0);
+
+/// 2026 head of household tax bracket 7 base tax amount: $191,171.00 (source: irs.gov)
+pub const HEAD_OF_HOUSEHOLD_BRACKET_7_BASE_TAX: Money = Money::new(191_171, 0);
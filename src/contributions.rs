@@ -0,0 +1,500 @@
+//! Module for annual contribution limits on 401(k), HSA and FSA deductions.
+//! Traditional and Roth 401(k) deferrals share the 402(g) elective deferral limit, while HSA and health FSA contributions each have their own limit (see `ContributionLimits` in the `tax_year` table).
+//! A paycheck never contributes more than what is left of a limit after the contributions already made this year, and a `ContributionSchedule` follows every pay period of the year to show in which pay period each limit is reached.
+
+use crate::EmploymentScenario;
+use crate::income::GrossPay;
+use crate::money::Money;
+use crate::projection::pay_dates;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::AddAssign;
+use std::str::FromStr;
+
+/// Plan whose contributions are limited each year
+/// # Variants
+/// * `ElectiveDeferral` - traditional and Roth 401(k) deferrals combined (IRC 402(g))
+/// * `Hsa` - health savings account contributions
+/// * `HealthFsa` - health flexible spending account contributions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ContributionPlan {
+    ElectiveDeferral,
+    Hsa,
+    HealthFsa,
+}
+
+impl ContributionPlan {
+    /// Every limited plan, in the order the limits are reported
+    pub const ALL: [ContributionPlan; 3] = [
+        ContributionPlan::ElectiveDeferral,
+        ContributionPlan::Hsa,
+        ContributionPlan::HealthFsa,
+    ];
+
+    /// Display name of the plan
+    pub fn name(&self) -> &'static str {
+        match self {
+            ContributionPlan::ElectiveDeferral => "401(k) Elective Deferrals",
+            ContributionPlan::Hsa => "HSA",
+            ContributionPlan::HealthFsa => "Health FSA",
+        }
+    }
+}
+
+impl fmt::Display for ContributionPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// HSA-eligible health plan coverage, which sets the HSA contribution limit
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HsaCoverage {
+    #[default]
+    SelfOnly,
+    Family,
+}
+
+impl FromStr for HsaCoverage {
+    type Err = String;
+
+    /// Parses "self-only" (or "self") or "family", case insensitive
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s
+            .trim()
+            .to_lowercase()
+            .replace(['-', '_', ' '], "")
+            .as_str()
+        {
+            "selfonly" | "self" => Ok(HsaCoverage::SelfOnly),
+            "family" => Ok(HsaCoverage::Family),
+            other => Err(format!(
+                "unknown HSA coverage '{other}' (expected self-only or family)"
+            )),
+        }
+    }
+}
+
+/// An amount for each limited plan, e.g. the contributions made so far this year
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContributionTotals {
+    pub elective_deferral: Money,
+    pub hsa: Money,
+    pub health_fsa: Money,
+}
+
+impl ContributionTotals {
+    /// The amount for a plan
+    pub fn get(&self, plan: ContributionPlan) -> Money {
+        match plan {
+            ContributionPlan::ElectiveDeferral => self.elective_deferral,
+            ContributionPlan::Hsa => self.hsa,
+            ContributionPlan::HealthFsa => self.health_fsa,
+        }
+    }
+
+    /// Add to the amount for a plan
+    pub fn add(&mut self, plan: ContributionPlan, amount: Money) {
+        match plan {
+            ContributionPlan::ElectiveDeferral => self.elective_deferral += amount,
+            ContributionPlan::Hsa => self.hsa += amount,
+            ContributionPlan::HealthFsa => self.health_fsa += amount,
+        }
+    }
+}
+
+impl AddAssign for ContributionTotals {
+    fn add_assign(&mut self, other: Self) {
+        for plan in ContributionPlan::ALL {
+            self.add(plan, other.get(plan));
+        }
+    }
+}
+
+/// Contributions of one pay period of a `ContributionSchedule`
+/// # Fields
+/// * `pay_period` - number of the pay period in the year, starting at 1
+/// * `pay_date` - pay date of the period, when the schedule was built from a first pay date
+/// * `elected` - contributions elected by the scenario's deductions
+/// * `contributed` - contributions actually taken, after capping at the annual limits
+/// * `year_to_date` - contributions made this year through the end of the period
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContributionPeriod {
+    pub pay_period: usize,
+    pub pay_date: Option<NaiveDate>,
+    pub elected: ContributionTotals,
+    pub contributed: ContributionTotals,
+    pub year_to_date: ContributionTotals,
+}
+
+/// An annual contribution limit reached during the year
+/// # Fields
+/// * `plan` - the limited plan
+/// * `limit` - the annual limit, catch-up contributions included and the employer's HSA contribution taken off
+/// * `pay_period` - number of the pay period in which the year-to-date contributions reach the limit
+/// * `pay_date` - pay date of that period, when known
+/// * `not_contributed` - contributions elected for the rest of the year that are not taken because of the limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct LimitReached {
+    pub plan: ContributionPlan,
+    pub limit: Money,
+    pub pay_period: usize,
+    pub pay_date: Option<NaiveDate>,
+    pub not_contributed: Money,
+}
+
+impl fmt::Display for LimitReached {
+    /// Formats as e.g. "401(k) Elective Deferrals reach the $24500.00 limit in pay period 23 (2026-11-13), $4100.00 of the elected contributions is not contributed"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} reach the ${} limit in pay period {}",
+            self.plan, self.limit, self.pay_period
+        )?;
+        if let Some(pay_date) = self.pay_date {
+            write!(f, " ({pay_date})")?;
+        }
+        if !self.not_contributed.is_zero() {
+            write!(
+                f,
+                ", ${} of the elected contributions is not contributed",
+                self.not_contributed
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Contributions to each limited plan for every pay period of a year
+/// # Fields
+/// * `limits` - the annual limit of each plan
+/// * `periods` - one `ContributionPeriod` for each pay period
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContributionSchedule {
+    pub limits: ContributionTotals,
+    pub periods: Vec<ContributionPeriod>,
+}
+
+impl ContributionSchedule {
+    /// Total contributions taken during the schedule
+    pub fn total_contributed(&self) -> ContributionTotals {
+        let mut total = ContributionTotals::default();
+        for period in &self.periods {
+            total += period.contributed;
+        }
+        total
+    }
+
+    /// The limits reached during the year, with the first pay period in which contributions to the plan reach the limit
+    pub fn limits_reached(&self) -> Vec<LimitReached> {
+        ContributionPlan::ALL
+            .into_iter()
+            .filter_map(|plan| {
+                let limit = self.limits.get(plan);
+                let reached = self.periods.iter().position(|period| {
+                    !period.elected.get(plan).is_zero() && period.year_to_date.get(plan) >= limit
+                })?;
+                let not_contributed = self.periods[reached..]
+                    .iter()
+                    .map(|period| period.elected.get(plan) - period.contributed.get(plan))
+                    .sum();
+                Some(LimitReached {
+                    plan,
+                    limit,
+                    pay_period: self.periods[reached].pay_period,
+                    pay_date: self.periods[reached].pay_date,
+                    not_contributed,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Amount of each deduction taken from one paycheck, with plan contributions capped at their annual limits
+/// # Fields
+/// * `pretax` - amount of each pre-tax deduction, in the scenario's order
+/// * `posttax` - amount of each post-tax deduction, in the scenario's order
+/// * `contributions` - contributions to each limited plan in the paycheck
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct CappedDeductions {
+    pub pretax: Vec<Money>,
+    pub posttax: Vec<Money>,
+    pub contributions: ContributionTotals,
+}
+
+impl EmploymentScenario {
+    /// Annual limit on the employee's contributions to a plan, with the catch-up contributions allowed at the scenario's `age` and the HSA limit for its `hsa_coverage`.
+    /// The employer's HSA contribution counts toward the HSA limit, so it is taken off the HSA limit.
+    pub fn contribution_limit(&self, plan: ContributionPlan) -> Money {
+        let limit = self
+            .tax_year
+            .contribution_limits
+            .limit(plan, self.hsa_coverage, self.age);
+        match plan {
+            ContributionPlan::Hsa => (limit - self.employer.hsa_seed).max(Money::ZERO),
+            _ => limit,
+        }
+    }
+
+    /// Amounts of the deductions taken from a paycheck with the given gross pay when `year_to_date_contributions` were made earlier in the year.
+    /// Pre-tax deductions are taken before post-tax deductions, so traditional 401(k) deferrals use the shared elective deferral limit before Roth 401(k) deferrals.
+    pub(crate) fn capped_deductions(
+        &self,
+        gross_pay: GrossPay,
+        year_to_date_contributions: ContributionTotals,
+    ) -> CappedDeductions {
+        let mut contributions = ContributionTotals::default();
+        let mut cap = |plan: Option<ContributionPlan>, amount: Money| match plan {
+            Some(plan) => {
+                let remaining = (self.contribution_limit(plan)
+                    - year_to_date_contributions.get(plan)
+                    - contributions.get(plan))
+                .max(Money::ZERO);
+                let amount = amount.min(remaining);
+                contributions.add(plan, amount);
+                amount
+            }
+            None => amount,
+        };
+        let pretax = self
+            .pretax_deductions
+            .get_pretax_deductions()
+            .iter()
+            .map(|deduction| cap(deduction.contribution_plan(), deduction.amount(gross_pay)))
+            .collect();
+        let posttax = self
+            .posttax_deductions
+            .get_posttax_deductions()
+            .iter()
+            .map(|deduction| cap(deduction.contribution_plan(), deduction.amount(gross_pay)))
+            .collect();
+        CappedDeductions {
+            pretax,
+            posttax,
+            contributions,
+        }
+    }
+
+    /// Contributions to each limited plan elected by the deductions of a paycheck with the given gross pay, before any limit
+    fn elected_contributions(&self, gross_pay: GrossPay) -> ContributionTotals {
+        let mut elected = ContributionTotals::default();
+        let pretax = self
+            .pretax_deductions
+            .get_pretax_deductions()
+            .iter()
+            .map(|deduction| (deduction.contribution_plan(), deduction.amount(gross_pay)));
+        let posttax = self
+            .posttax_deductions
+            .get_posttax_deductions()
+            .iter()
+            .map(|deduction| (deduction.contribution_plan(), deduction.amount(gross_pay)));
+        for (plan, amount) in pretax.chain(posttax) {
+            if let Some(plan) = plan {
+                elected.add(plan, amount);
+            }
+        }
+        elected
+    }
+
+    /// Follows the 401(k), HSA and FSA contributions through every pay period of the year, starting from the scenario's `year_to_date_contributions`.
+    /// With a `first_pay_date` the schedule covers every pay date from it to the end of its calendar year; otherwise it covers a full year of pay periods for the `pay_frequency`.
+    /// Contributions stop once a plan reaches its annual limit, and `ContributionSchedule::limits_reached` reports in which pay period that happens.
+    ///
+    /// # Example
+    /// ```
+    /// use paycheck_utils::*;
+    ///
+    /// // $1,000 to a traditional 401(k) every two weeks would be $26,000 over the year
+    /// let scenario = EmploymentScenario::new(
+    ///     Money::new(50, 0),
+    ///     40.0,
+    ///     FilingStatus::Single,
+    ///     PreTaxDeductions::new(vec![PreTaxDeduction::Traditional401K(Some(Money::new(1000, 0).into()))]),
+    ///     PostTaxDeductions::default(),
+    ///     Expenses::default(),
+    /// );
+    /// let schedule = scenario.contribution_schedule(None);
+    /// assert_eq!(schedule.total_contributed().elective_deferral, Money::new(24_500, 0));
+    ///
+    /// let limits = schedule.limits_reached();
+    /// assert_eq!(limits[0].plan, ContributionPlan::ElectiveDeferral);
+    /// assert_eq!(limits[0].pay_period, 25);
+    /// assert_eq!(limits[0].not_contributed, Money::new(1500, 0));
+    /// ```
+    pub fn contribution_schedule(&self, first_pay_date: Option<NaiveDate>) -> ContributionSchedule {
+        let pay_dates: Vec<Option<NaiveDate>> = match first_pay_date {
+            Some(first_pay_date) => pay_dates(first_pay_date, self.pay_frequency)
+                .into_iter()
+                .map(Some)
+                .collect(),
+            None => vec![None; self.pay_frequency.periods_per_year() as usize],
+        };
+        let gross_pay = self.pay_basis.gross_pay(self.pay_frequency);
+        let elected = self.elected_contributions(gross_pay);
+
+        let mut limits = ContributionTotals::default();
+        for plan in ContributionPlan::ALL {
+            limits.add(plan, self.contribution_limit(plan));
+        }
+
+        let mut year_to_date = self.year_to_date_contributions;
+        let periods = pay_dates
+            .into_iter()
+            .enumerate()
+            .map(|(index, pay_date)| {
+                let contributed = self
+                    .capped_deductions(gross_pay, year_to_date)
+                    .contributions;
+                year_to_date += contributed;
+                ContributionPeriod {
+                    pay_period: index + 1,
+                    pay_date,
+                    elected,
+                    contributed,
+                    year_to_date,
+                }
+            })
+            .collect();
+
+        ContributionSchedule { limits, periods }
+    }
+}
+
+// UNIT TESTS FOR CONTRIBUTIONS MODULE
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::ScenarioBuilder;
+    use crate::*;
+
+    #[test]
+    fn test_contribution_limit_catch_up_by_age() {
+        let mut scenario = ScenarioBuilder::hourly(50, 40.0).build();
+        let elective_deferral = |scenario: &EmploymentScenario| {
+            scenario.contribution_limit(ContributionPlan::ElectiveDeferral)
+        };
+        assert_eq!(elective_deferral(&scenario), Money::new(24_500, 0));
+        for (age, limit) in [
+            (49, 24_500),
+            (50, 32_500),
+            (59, 32_500),
+            (60, 35_750),
+            (63, 35_750),
+            (64, 32_500),
+        ] {
+            scenario.age = Some(age);
+            assert_eq!(
+                elective_deferral(&scenario),
+                Money::new(limit, 0),
+                "age {age}"
+            );
+        }
+
+        scenario.age = Some(54);
+        scenario.hsa_coverage = HsaCoverage::Family;
+        assert_eq!(
+            scenario.contribution_limit(ContributionPlan::Hsa),
+            Money::new(8_750, 0)
+        );
+        scenario.age = Some(55);
+        assert_eq!(
+            scenario.contribution_limit(ContributionPlan::Hsa),
+            Money::new(9_750, 0)
+        );
+        assert_eq!(
+            scenario.contribution_limit(ContributionPlan::HealthFsa),
+            Money::new(3_400, 0)
+        );
+    }
+
+    #[test]
+    fn test_traditional_and_roth_share_the_elective_deferral_limit() {
+        let scenario = ScenarioBuilder::hourly(50, 40.0)
+            .pretax(vec![PreTaxDeduction::Traditional401K(Some(
+                Money::new(600, 0).into(),
+            ))])
+            .posttax(vec![PostTaxDeduction::Roth401K(Some(
+                Money::new(400, 0).into(),
+            ))])
+            .build();
+        let schedule = scenario.contribution_schedule(None);
+        assert_eq!(schedule.periods.len(), 26);
+        assert_eq!(
+            schedule.total_contributed().elective_deferral,
+            Money::new(24_500, 0)
+        );
+
+        // 24 paychecks contribute $24,000, the 25th only $500 of its $1,000
+        let period = &schedule.periods[24];
+        assert_eq!(period.contributed.elective_deferral, Money::new(500, 0));
+        let limits = schedule.limits_reached();
+        assert_eq!(limits.len(), 1);
+        assert_eq!(limits[0].pay_period, 25);
+        assert_eq!(limits[0].not_contributed, Money::new(1_500, 0));
+
+        // the traditional deferral is taken first, so the Roth deferral is cut first
+        let last = scenario.calculate_paycheck_breakdown_for(
+            scenario.pay_basis.gross_pay(scenario.pay_frequency),
+            Money::ZERO,
+            ContributionTotals {
+                elective_deferral: Money::new(24_000, 0),
+                ..ContributionTotals::default()
+            },
+        );
+        assert_eq!(last.pretax_deductions[0].amount, Money::new(500, 0));
+        assert_eq!(last.posttax_deductions[0].amount, Money::ZERO);
+    }
+
+    #[test]
+    fn test_contribution_schedule_from_pay_dates_and_year_to_date() {
+        let mut scenario = ScenarioBuilder::hourly(50, 40.0)
+            .pretax(vec![
+                PreTaxDeduction::HSA(Some(Money::new(400, 0).into())),
+                PreTaxDeduction::FSA(Some(Money::new(100, 0).into())),
+                PreTaxDeduction::Medical(Some(Money::new(100, 0).into())),
+            ])
+            .build();
+        scenario.year_to_date_contributions.hsa = Money::new(2_000, 0);
+
+        // 20 pay dates from June 5th, and $2,400 of HSA room left
+        let schedule = scenario.contribution_schedule(NaiveDate::from_ymd_opt(2026, 6, 5));
+        assert_eq!(schedule.periods.len(), 15);
+        let limits = schedule.limits_reached();
+        assert_eq!(limits.len(), 1);
+        assert_eq!(limits[0].plan, ContributionPlan::Hsa);
+        assert_eq!(limits[0].pay_period, 6);
+        assert_eq!(limits[0].pay_date, NaiveDate::from_ymd_opt(2026, 8, 14));
+        assert_eq!(limits[0].not_contributed, Money::new(3_600, 0));
+        assert_eq!(
+            schedule.total_contributed().health_fsa,
+            Money::new(1_500, 0)
+        );
+
+        // net pay rises once HSA contributions stop
+        let projection =
+            scenario.project_monthly_income(NaiveDate::from_ymd_opt(2026, 6, 5).unwrap());
+        let june = &projection.months[5];
+        let september = &projection.months[8];
+        assert!(september.net_income > june.net_income);
+    }
+
+    #[test]
+    fn test_no_limit_reached() {
+        let scenario = ScenarioBuilder::hourly(50, 40.0)
+            .pretax(vec![PreTaxDeduction::Traditional401K(Some(
+                DeductionAmount::PercentOfGross(6.0),
+            ))])
+            .build();
+        let schedule = scenario.contribution_schedule(None);
+        assert!(schedule.limits_reached().is_empty());
+        assert_eq!(
+            schedule.total_contributed().elective_deferral,
+            Money::new(6_240, 0)
+        );
+    }
+}
@@ -1,37 +1,398 @@
 //! Module for handling paycheck deductions.
-//! Defines structures and functions for managing pre-tax and post-tax deductions.
+//! Defines structures and functions for managing pre-tax and post-tax deductions, including user-named custom deductions with a declared tax treatment.
+//! A deduction is either a flat amount per paycheck or a percentage of pay, resolved against each paycheck's gross pay.
+
+use crate::contributions::ContributionPlan;
+use crate::garnishments::GarnishmentKind;
+use crate::income::GrossPay;
+use crate::money::Money;
+use crate::utils::{parse_custom_amount, parse_kind_amount};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// How a pre-tax deduction lowers the wages that are taxed
+/// # Variants
+/// * `FederalOnly` - lowers the wages subject to federal income tax only; Social Security and Medicare are still withheld on the deducted amount (e.g. 403(b) and 457(b) deferrals)
+/// * `FederalAndFica` - lowers the wages subject to federal income tax, Social Security and Medicare (e.g. Section 125 cafeteria plans, Section 132 commuter benefits, dependent care FSA)
+///
+/// Parses from "federal-only" or "federal-and-fica" (case insensitive, with or without hyphens).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TaxTreatment {
+    FederalOnly,
+    #[default]
+    FederalAndFica,
+}
+
+impl FromStr for TaxTreatment {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s
+            .trim()
+            .to_lowercase()
+            .replace(['-', '_', ' '], "")
+            .as_str()
+        {
+            "federalonly" | "federal" => Ok(TaxTreatment::FederalOnly),
+            "federalandfica" | "federal+fica" | "fica" => Ok(TaxTreatment::FederalAndFica),
+            other => Err(format!(
+                "unknown tax treatment '{other}' (expected federal-only or federal-and-fica)"
+            )),
+        }
+    }
+}
+
+/// How much a deduction takes from each paycheck
+/// # Variants
+/// * `Flat` - a fixed dollar amount per paycheck
+/// * `PercentOfGross` - a percentage (e.g. `6.0` for 6%) of the paycheck's gross pay, overtime included
+/// * `PercentOfRegularPay` - a percentage of the paycheck's regular pay, for plans whose eligible pay excludes overtime
+///
+/// In a scenario file a flat amount is a number (`amount = 200`) and a percentage is a table (`amount = { percent_of_gross = 6 }`).
+/// Parses from a dollar amount (`200`, `$200.00`), `6%` or `6%gross` for a percentage of gross pay, or `6%regular` for a percentage of regular pay.
+/// Percentages must be between 0% and 100%, whether parsed or read from a scenario file.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", try_from = "DeductionAmountFile")]
+pub enum DeductionAmount {
+    PercentOfGross(f64),
+    PercentOfRegularPay(f64),
+    #[serde(untagged)]
+    Flat(Money),
+}
+
+/// A `DeductionAmount` as written in a scenario file, before its percentage is checked
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum DeductionAmountFile {
+    PercentOfGross(f64),
+    PercentOfRegularPay(f64),
+    #[serde(untagged)]
+    Flat(Money),
+}
+
+impl TryFrom<DeductionAmountFile> for DeductionAmount {
+    type Error = String;
+
+    fn try_from(amount: DeductionAmountFile) -> Result<Self, Self::Error> {
+        match amount {
+            DeductionAmountFile::Flat(amount) => Ok(DeductionAmount::Flat(amount)),
+            DeductionAmountFile::PercentOfGross(percent) => {
+                validate_percent(percent).map(DeductionAmount::PercentOfGross)
+            }
+            DeductionAmountFile::PercentOfRegularPay(percent) => {
+                validate_percent(percent).map(DeductionAmount::PercentOfRegularPay)
+            }
+        }
+    }
+}
+
+/// Checks that a deduction percentage is between 0% and 100%
+fn validate_percent(percent: f64) -> Result<f64, String> {
+    if (0.0..=100.0).contains(&percent) {
+        Ok(percent)
+    } else {
+        Err(format!(
+            "invalid percentage {percent}% (expected 0% to 100%)"
+        ))
+    }
+}
+
+impl DeductionAmount {
+    /// The amount taken from a paycheck with the given gross pay, rounded half up to the cent
+    /// # Example
+    /// ```
+    /// use paycheck_utils::{DeductionAmount, GrossPay, Money};
+    ///
+    /// let gross_pay = GrossPay { regular: Money::new(2000, 0), overtime: Money::new(375, 0) };
+    /// assert_eq!(DeductionAmount::PercentOfGross(6.0).resolve(gross_pay), Money::new(142, 50));
+    /// assert_eq!(DeductionAmount::PercentOfRegularPay(6.0).resolve(gross_pay), Money::new(120, 0));
+    /// assert_eq!(DeductionAmount::Flat(Money::new(200, 0)).resolve(gross_pay), Money::new(200, 0));
+    /// ```
+    pub fn resolve(&self, gross_pay: GrossPay) -> Money {
+        match self {
+            DeductionAmount::Flat(amount) => *amount,
+            DeductionAmount::PercentOfGross(percent) => gross_pay.total().multiply(percent / 100.0),
+            DeductionAmount::PercentOfRegularPay(percent) => {
+                gross_pay.regular.multiply(percent / 100.0)
+            }
+        }
+    }
+}
+
+impl From<Money> for DeductionAmount {
+    fn from(amount: Money) -> Self {
+        DeductionAmount::Flat(amount)
+    }
+}
+
+impl FromStr for DeductionAmount {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((percent, base)) = s.split_once('%') else {
+            return s
+                .parse::<Money>()
+                .map(DeductionAmount::Flat)
+                .map_err(|error| error.to_string());
+        };
+        let percent = percent
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| format!("invalid percentage '{s}' (expected 0% to 100%)"))
+            .and_then(validate_percent)?;
+        match base.trim().to_lowercase().as_str() {
+            "" | "gross" => Ok(DeductionAmount::PercentOfGross(percent)),
+            "regular" => Ok(DeductionAmount::PercentOfRegularPay(percent)),
+            other => Err(format!(
+                "unknown percentage base '{other}' in '{s}' (expected gross or regular)"
+            )),
+        }
+    }
+}
 
 /// Pre-tax deductions are applied before federal tax calculations.
+/// Every kind lowers the wages subject to federal income tax. Medical, dental, vision, HSA and FSA (Section 125 cafeteria plan) deductions also lower the wages subject to Social Security and Medicare,
+/// while Social Security and Medicare are still withheld on traditional 401(k) deferrals (see `PreTaxDeduction::treatment`). A `Custom` deduction (e.g. commuter benefits, dependent care FSA, 403(b), 457(b)) declares its own `TaxTreatment`.
+/// In a scenario file a custom deduction is written without a kind, e.g. `{ name = "457(b)", amount = 300, treatment = "federal-only" }` (the treatment defaults to federal-and-fica).
+///
+/// Each amount is a `DeductionAmount`: a flat amount per paycheck or a percentage of pay (e.g. `{ kind = "traditional401k", amount = { percent_of_gross = 6 } }`).
 ///
-#[derive(Debug)]
+/// Parses from `KIND=AMOUNT` text (e.g. `medical=150` or `traditional401k=6%`), where the kind is one of medical, dental, vision, traditional401k, hsa or fsa,
+/// or from `custom:NAME=AMOUNT` or `custom:NAME:TREATMENT=AMOUNT` text for a custom deduction (e.g. `custom:457(b):federal-only=300`).
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "amount", rename_all = "lowercase")]
 pub enum PreTaxDeduction {
-    Medical(Option<f32>),
-    Dental(Option<f32>),
-    Vision(Option<f32>),
-    Traditional401K(Option<f32>),
-    HSA(Option<f32>),
-    FSA(Option<f32>),
+    Medical(Option<DeductionAmount>),
+    Dental(Option<DeductionAmount>),
+    Vision(Option<DeductionAmount>),
+    Traditional401K(Option<DeductionAmount>),
+    HSA(Option<DeductionAmount>),
+    FSA(Option<DeductionAmount>),
+    #[serde(untagged)]
+    Custom {
+        name: String,
+        amount: Option<DeductionAmount>,
+        #[serde(default)]
+        treatment: TaxTreatment,
+    },
 }
 
 /// Post-tax deductions are applied after federal tax calculations
-#[derive(Debug)]
+/// A `Custom` deduction covers anything else taken from net pay (e.g. union dues, ESPP, charitable payroll giving), written as `{ name = "Union Dues", amount = 40 }` in a scenario file.
+///
+/// Each amount is a `DeductionAmount`: a flat amount per paycheck or a percentage of pay.
+///
+/// `ChildSupport`, `FederalTaxLevy`, `StudentLoan` and `WageGarnishment` (a creditor garnishment) are garnishment orders: the amount is what the order asks for, and the paycheck withholds no more than the limits in the `garnishments` module allow.
+///
+/// Parses from `KIND=AMOUNT` text (e.g. `roth401k=100` or `roth401k=4%`), where the kind is one of roth401k, life, add, std, ltd, childsupport, taxlevy, studentloan or garnishment (the `voluntary` prefix is optional),
+/// or from `custom:NAME=AMOUNT` text for a custom deduction (e.g. `custom:Union Dues=40`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "amount", rename_all = "lowercase")]
 pub enum PostTaxDeduction {
-    Roth401K(Option<f32>),
-    VoluntaryLife(Option<f32>),
-    VoluntaryADD(Option<f32>),
-    VoluntarySTD(Option<f32>),
-    VoluntaryLTD(Option<f32>),
-    WageGarnishment(Option<f32>), // e.g., child support, alimony
+    Roth401K(Option<DeductionAmount>),
+    VoluntaryLife(Option<DeductionAmount>),
+    VoluntaryADD(Option<DeductionAmount>),
+    VoluntarySTD(Option<DeductionAmount>),
+    VoluntaryLTD(Option<DeductionAmount>),
+    ChildSupport(Option<DeductionAmount>), // child support or alimony order
+    FederalTaxLevy(Option<DeductionAmount>),
+    StudentLoan(Option<DeductionAmount>), // administrative wage garnishment of a defaulted federal student loan
+    WageGarnishment(Option<DeductionAmount>), // creditor garnishment, e.g. a judgment for a consumer debt
+    #[serde(untagged)]
+    Custom {
+        name: String,
+        amount: Option<DeductionAmount>,
+    },
+}
+
+impl PreTaxDeduction {
+    /// Display name of the deduction (e.g., for a paystub line)
+    pub fn name(&self) -> &str {
+        match self {
+            PreTaxDeduction::Medical(_) => "Medical",
+            PreTaxDeduction::Dental(_) => "Dental",
+            PreTaxDeduction::Vision(_) => "Vision",
+            PreTaxDeduction::Traditional401K(_) => "Traditional 401(k)",
+            PreTaxDeduction::HSA(_) => "HSA",
+            PreTaxDeduction::FSA(_) => "FSA",
+            PreTaxDeduction::Custom { name, .. } => name,
+        }
+    }
+
+    /// Amount of the deduction taken from a paycheck with the given gross pay (no amount is treated as zero)
+    pub fn amount(&self, gross_pay: GrossPay) -> Money {
+        match self {
+            PreTaxDeduction::Medical(amount)
+            | PreTaxDeduction::Dental(amount)
+            | PreTaxDeduction::Vision(amount)
+            | PreTaxDeduction::Traditional401K(amount)
+            | PreTaxDeduction::HSA(amount)
+            | PreTaxDeduction::FSA(amount)
+            | PreTaxDeduction::Custom { amount, .. } => amount
+                .map(|amount| amount.resolve(gross_pay))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Which taxes the deduction is exempt from
+    /// * `FederalOnly` - traditional 401(k) deferrals, which are still subject to Social Security and Medicare
+    /// * `FederalAndFica` - medical, dental, vision, HSA and FSA contributions through a Section 125 cafeteria plan
+    /// * a `Custom` deduction's declared treatment
+    pub fn treatment(&self) -> TaxTreatment {
+        match self {
+            PreTaxDeduction::Traditional401K(_) => TaxTreatment::FederalOnly,
+            PreTaxDeduction::Medical(_)
+            | PreTaxDeduction::Dental(_)
+            | PreTaxDeduction::Vision(_)
+            | PreTaxDeduction::HSA(_)
+            | PreTaxDeduction::FSA(_) => TaxTreatment::FederalAndFica,
+            PreTaxDeduction::Custom { treatment, .. } => *treatment,
+        }
+    }
+
+    /// Plan whose annual contribution limit applies to the deduction, if any
+    pub fn contribution_plan(&self) -> Option<ContributionPlan> {
+        match self {
+            PreTaxDeduction::Traditional401K(_) => Some(ContributionPlan::ElectiveDeferral),
+            PreTaxDeduction::HSA(_) => Some(ContributionPlan::Hsa),
+            PreTaxDeduction::FSA(_) => Some(ContributionPlan::HealthFsa),
+            _ => None,
+        }
+    }
+}
+
+impl PostTaxDeduction {
+    /// Display name of the deduction (e.g., for a paystub line)
+    pub fn name(&self) -> &str {
+        match self {
+            PostTaxDeduction::Roth401K(_) => "Roth 401(k)",
+            PostTaxDeduction::VoluntaryLife(_) => "Voluntary Life",
+            PostTaxDeduction::VoluntaryADD(_) => "Voluntary AD&D",
+            PostTaxDeduction::VoluntarySTD(_) => "Voluntary STD",
+            PostTaxDeduction::VoluntaryLTD(_) => "Voluntary LTD",
+            PostTaxDeduction::ChildSupport(_) => "Child Support",
+            PostTaxDeduction::FederalTaxLevy(_) => "Federal Tax Levy",
+            PostTaxDeduction::StudentLoan(_) => "Student Loan Garnishment",
+            PostTaxDeduction::WageGarnishment(_) => "Wage Garnishment",
+            PostTaxDeduction::Custom { name, .. } => name,
+        }
+    }
+
+    /// Amount of the deduction taken from a paycheck with the given gross pay (no amount is treated as zero)
+    pub fn amount(&self, gross_pay: GrossPay) -> Money {
+        match self {
+            PostTaxDeduction::Roth401K(amount)
+            | PostTaxDeduction::VoluntaryLife(amount)
+            | PostTaxDeduction::VoluntaryADD(amount)
+            | PostTaxDeduction::VoluntarySTD(amount)
+            | PostTaxDeduction::VoluntaryLTD(amount)
+            | PostTaxDeduction::ChildSupport(amount)
+            | PostTaxDeduction::FederalTaxLevy(amount)
+            | PostTaxDeduction::StudentLoan(amount)
+            | PostTaxDeduction::WageGarnishment(amount)
+            | PostTaxDeduction::Custom { amount, .. } => amount
+                .map(|amount| amount.resolve(gross_pay))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Plan whose annual contribution limit applies to the deduction, if any (Roth 401(k) deferrals share the elective deferral limit with traditional 401(k) deferrals)
+    pub fn contribution_plan(&self) -> Option<ContributionPlan> {
+        match self {
+            PostTaxDeduction::Roth401K(_) => Some(ContributionPlan::ElectiveDeferral),
+            _ => None,
+        }
+    }
+
+    /// Kind of garnishment order, if the deduction is one
+    pub fn garnishment_kind(&self) -> Option<GarnishmentKind> {
+        match self {
+            PostTaxDeduction::ChildSupport(_) => Some(GarnishmentKind::ChildSupport),
+            PostTaxDeduction::FederalTaxLevy(_) => Some(GarnishmentKind::FederalTaxLevy),
+            PostTaxDeduction::StudentLoan(_) => Some(GarnishmentKind::StudentLoan),
+            PostTaxDeduction::WageGarnishment(_) => Some(GarnishmentKind::Creditor),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for PreTaxDeduction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(custom) = parse_custom_amount(s, true) {
+            let (name, treatment, amount) = custom?;
+            return Ok(PreTaxDeduction::Custom {
+                name,
+                amount: Some(amount),
+                treatment: treatment
+                    .map(|treatment| treatment.parse::<TaxTreatment>())
+                    .transpose()?
+                    .unwrap_or_default(),
+            });
+        }
+
+        let (kind, amount) = parse_kind_amount(s)?;
+        let amount = Some(amount);
+        match kind.as_str() {
+            "medical" => Ok(PreTaxDeduction::Medical(amount)),
+            "dental" => Ok(PreTaxDeduction::Dental(amount)),
+            "vision" => Ok(PreTaxDeduction::Vision(amount)),
+            "traditional401k" | "401k" => Ok(PreTaxDeduction::Traditional401K(amount)),
+            "hsa" => Ok(PreTaxDeduction::HSA(amount)),
+            "fsa" => Ok(PreTaxDeduction::FSA(amount)),
+            _ => Err(format!(
+                "unknown pre-tax deduction in '{s}' (expected medical, dental, vision, traditional401k, hsa, fsa or custom:NAME)"
+            )),
+        }
+    }
+}
+
+impl FromStr for PostTaxDeduction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(custom) = parse_custom_amount(s, false) {
+            let (name, _, amount) = custom?;
+            return Ok(PostTaxDeduction::Custom {
+                name,
+                amount: Some(amount),
+            });
+        }
+
+        let (kind, amount) = parse_kind_amount(s)?;
+        let amount = Some(amount);
+        match kind.trim_start_matches("voluntary") {
+            "roth401k" | "roth" => Ok(PostTaxDeduction::Roth401K(amount)),
+            "life" => Ok(PostTaxDeduction::VoluntaryLife(amount)),
+            "add" | "ad&d" => Ok(PostTaxDeduction::VoluntaryADD(amount)),
+            "std" => Ok(PostTaxDeduction::VoluntarySTD(amount)),
+            "ltd" => Ok(PostTaxDeduction::VoluntaryLTD(amount)),
+            "childsupport" | "support" => Ok(PostTaxDeduction::ChildSupport(amount)),
+            "federaltaxlevy" | "taxlevy" | "levy" => Ok(PostTaxDeduction::FederalTaxLevy(amount)),
+            "studentloan" | "studentloangarnishment" => Ok(PostTaxDeduction::StudentLoan(amount)),
+            "garnishment" | "wagegarnishment" | "creditor" => {
+                Ok(PostTaxDeduction::WageGarnishment(amount))
+            }
+            _ => Err(format!(
+                "unknown post-tax deduction in '{s}' (expected roth401k, life, add, std, ltd, childsupport, taxlevy, studentloan, garnishment or custom:NAME)"
+            )),
+        }
+    }
 }
 
 /// Struct to manage a collection of pre-tax deductions
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct PreTaxDeductions {
     pretax_deductions: Vec<PreTaxDeduction>,
 }
 
 /// Struct to manage a collection of post-tax deductions
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct PostTaxDeductions {
     posttax_deductions: Vec<PostTaxDeduction>,
 }
@@ -53,18 +414,21 @@ impl PreTaxDeductions {
         &self.pretax_deductions
     }
 
-    /// Calculate the total amount of pre-tax deductions
-    pub fn total_pretax_deductions(&self) -> f32 {
+    /// Calculate the total amount of pre-tax deductions taken from a paycheck with the given gross pay (all of them lower the wages subject to federal income tax)
+    pub fn total_pretax_deductions(&self, gross_pay: GrossPay) -> Money {
+        self.pretax_deductions
+            .iter()
+            .map(|deduction| deduction.amount(gross_pay))
+            .sum()
+    }
+
+    /// Calculate the total amount of the pre-tax deductions that also lower the wages subject to Social Security and Medicare (`TaxTreatment::FederalAndFica`)
+    pub fn total_fica_exempt_deductions(&self, gross_pay: GrossPay) -> Money {
         self.pretax_deductions
             .iter()
-            .fold(0.0, |acc, deduction| match deduction {
-                PreTaxDeduction::Medical(amount)
-                | PreTaxDeduction::Dental(amount)
-                | PreTaxDeduction::Vision(amount)
-                | PreTaxDeduction::Traditional401K(amount)
-                | PreTaxDeduction::HSA(amount)
-                | PreTaxDeduction::FSA(amount) => acc + amount.unwrap_or(0.0),
-            })
+            .filter(|deduction| deduction.treatment() == TaxTreatment::FederalAndFica)
+            .map(|deduction| deduction.amount(gross_pay))
+            .sum()
     }
 }
 
@@ -86,18 +450,12 @@ impl PostTaxDeductions {
         &self.posttax_deductions
     }
 
-    /// Calculate the total amount of post-tax deductions
-    pub fn total_posttax_deductions(&self) -> f32 {
+    /// Calculate the total amount of post-tax deductions taken from a paycheck with the given gross pay
+    pub fn total_posttax_deductions(&self, gross_pay: GrossPay) -> Money {
         self.posttax_deductions
             .iter()
-            .fold(0.0, |acc, deduction| match deduction {
-                PostTaxDeduction::Roth401K(amount)
-                | PostTaxDeduction::VoluntaryLife(amount)
-                | PostTaxDeduction::VoluntaryADD(amount)
-                | PostTaxDeduction::VoluntarySTD(amount)
-                | PostTaxDeduction::VoluntaryLTD(amount)
-                | PostTaxDeduction::WageGarnishment(amount) => acc + amount.unwrap_or(0.0),
-            })
+            .map(|deduction| deduction.amount(gross_pay))
+            .sum()
     }
 }
 
@@ -111,61 +469,290 @@ mod tests {
     #[test]
     fn test_total_pretax_deductions() {
         let deductions = PreTaxDeductions::new(vec![
-            Medical(Some(150.0)),
-            Dental(Some(50.0)),
-            Traditional401K(Some(200.0)),
+            Medical(Some(Money::new(150, 0).into())),
+            Dental(Some(Money::new(50, 0).into())),
+            Traditional401K(Some(Money::new(200, 0).into())),
         ]);
-        let total = deductions.total_pretax_deductions();
-        assert_eq!(total, 400.0);
+        let total = deductions.total_pretax_deductions(GrossPay::default());
+        assert_eq!(total, Money::new(400, 0));
     }
     #[test]
     fn test_get_pretax_deductions() {
         let deductions = PreTaxDeductions::new(vec![
-            Medical(Some(150.0)),
-            Dental(Some(50.0)),
-            Traditional401K(Some(200.0)),
+            Medical(Some(Money::new(150, 0).into())),
+            Dental(Some(Money::new(50, 0).into())),
+            Traditional401K(Some(Money::new(200, 0).into())),
         ]);
         let pretax_list = deductions.get_pretax_deductions();
         assert_eq!(pretax_list.len(), 3);
     }
     #[test]
     fn test_add_pretax_deductions() {
-        let mut deductions = PreTaxDeductions::new(vec![Medical(Some(150.0))]);
-        deductions.add_pretax_deductions(vec![Dental(Some(50.0)), Traditional401K(Some(200.0))]);
-        let total = deductions.total_pretax_deductions();
-        assert_eq!(total, 400.0);
+        let mut deductions = PreTaxDeductions::new(vec![Medical(Some(Money::new(150, 0).into()))]);
+        deductions.add_pretax_deductions(vec![
+            Dental(Some(Money::new(50, 0).into())),
+            Traditional401K(Some(Money::new(200, 0).into())),
+        ]);
+        let total = deductions.total_pretax_deductions(GrossPay::default());
+        assert_eq!(total, Money::new(400, 0));
     }
     #[test]
     fn test_total_posttax_deductions() {
         let deductions = PostTaxDeductions::new(vec![
-            VoluntaryLife(Some(30.0)),
-            VoluntarySTD(Some(22.0)),
-            VoluntaryLTD(Some(34.0)),
-            WageGarnishment(Some(600.0)),
+            VoluntaryLife(Some(Money::new(30, 0).into())),
+            VoluntarySTD(Some(Money::new(22, 0).into())),
+            VoluntaryLTD(Some(Money::new(34, 0).into())),
+            WageGarnishment(Some(Money::new(600, 0).into())),
         ]);
-        let total = deductions.total_posttax_deductions();
-        assert_eq!(total, 686.0);
+        let total = deductions.total_posttax_deductions(GrossPay::default());
+        assert_eq!(total, Money::new(686, 0));
     }
     #[test]
     fn test_get_posttax_deductions() {
         let deductions = PostTaxDeductions::new(vec![
-            VoluntaryLife(Some(30.0)),
-            VoluntarySTD(Some(22.0)),
-            VoluntaryLTD(Some(34.0)),
-            WageGarnishment(Some(600.0)),
+            VoluntaryLife(Some(Money::new(30, 0).into())),
+            VoluntarySTD(Some(Money::new(22, 0).into())),
+            VoluntaryLTD(Some(Money::new(34, 0).into())),
+            WageGarnishment(Some(Money::new(600, 0).into())),
         ]);
         let posttax_list = deductions.get_posttax_deductions();
         assert_eq!(posttax_list.len(), 4);
     }
     #[test]
     fn test_add_posttax_deductions() {
-        let mut deductions = PostTaxDeductions::new(vec![VoluntaryLife(Some(30.0))]);
+        let mut deductions =
+            PostTaxDeductions::new(vec![VoluntaryLife(Some(Money::new(30, 0).into()))]);
         deductions.add_posttax_deductions(vec![
-            VoluntarySTD(Some(22.0)),
-            VoluntaryLTD(Some(34.0)),
-            WageGarnishment(Some(600.0)),
+            VoluntarySTD(Some(Money::new(22, 0).into())),
+            VoluntaryLTD(Some(Money::new(34, 0).into())),
+            WageGarnishment(Some(Money::new(600, 0).into())),
         ]);
-        let total = deductions.total_posttax_deductions();
-        assert_eq!(total, 686.0);
+        let total = deductions.total_posttax_deductions(GrossPay::default());
+        assert_eq!(total, Money::new(686, 0));
+    }
+
+    #[test]
+    fn test_parse_deductions_from_kind_amount() {
+        let pretax: PreTaxDeduction = "Traditional-401k=200".parse().unwrap();
+        assert_eq!(pretax.name(), "Traditional 401(k)");
+        assert_eq!(pretax.amount(GrossPay::default()), Money::new(200, 0));
+        let posttax: PostTaxDeduction = "voluntary_life=30.50".parse().unwrap();
+        assert_eq!(posttax.name(), "Voluntary Life");
+        assert_eq!(posttax.amount(GrossPay::default()), Money::new(30, 50));
+
+        assert!("dental".parse::<PreTaxDeduction>().is_err());
+        assert!("pension=100".parse::<PreTaxDeduction>().is_err());
+        assert!("life=abc".parse::<PostTaxDeduction>().is_err());
+
+        for (text, kind) in [
+            ("child-support=400", GarnishmentKind::ChildSupport),
+            ("taxlevy=250", GarnishmentKind::FederalTaxLevy),
+            ("student_loan=100", GarnishmentKind::StudentLoan),
+            ("garnishment=600", GarnishmentKind::Creditor),
+        ] {
+            let posttax: PostTaxDeduction = text.parse().unwrap();
+            assert_eq!(posttax.garnishment_kind(), Some(kind), "{text}");
+        }
+        assert_eq!(posttax.garnishment_kind(), None);
+    }
+
+    #[test]
+    fn test_pretax_deduction_tax_treatment() {
+        let deductions = PreTaxDeductions::new(vec![
+            Medical(Some(Money::new(100, 0).into())),
+            Dental(Some(Money::new(50, 0).into())),
+            Vision(Some(Money::new(25, 0).into())),
+            Traditional401K(Some(Money::new(200, 0).into())),
+            HSA(Some(Money::new(150, 0).into())),
+            FSA(Some(Money::new(40, 0).into())),
+        ]);
+        assert_eq!(Traditional401K(None).treatment(), TaxTreatment::FederalOnly);
+        assert_eq!(HSA(None).treatment(), TaxTreatment::FederalAndFica);
+        assert_eq!(
+            deductions.total_pretax_deductions(GrossPay::default()),
+            Money::new(565, 0)
+        );
+        // everything but the 401(k) is a Section 125 deduction
+        assert_eq!(
+            deductions.total_fica_exempt_deductions(GrossPay::default()),
+            Money::new(365, 0)
+        );
+    }
+
+    #[test]
+    fn test_custom_deductions() {
+        let pretax = PreTaxDeductions::new(vec![
+            Medical(Some(Money::new(150, 0).into())),
+            "custom:Commuter=100".parse().unwrap(),
+            "custom:457(b):federal-only=300".parse().unwrap(),
+        ]);
+        let commuter = &pretax.get_pretax_deductions()[1];
+        assert_eq!(commuter.name(), "Commuter");
+        assert_eq!(commuter.treatment(), TaxTreatment::FederalAndFica);
+        assert_eq!(
+            pretax.get_pretax_deductions()[2].treatment(),
+            TaxTreatment::FederalOnly
+        );
+        assert_eq!(
+            pretax.total_pretax_deductions(GrossPay::default()),
+            Money::new(550, 0)
+        );
+        assert_eq!(
+            pretax.total_fica_exempt_deductions(GrossPay::default()),
+            Money::new(250, 0)
+        );
+
+        let posttax = PostTaxDeductions::new(vec![
+            VoluntaryLife(Some(Money::new(30, 0).into())),
+            "custom:Union Dues=40".parse().unwrap(),
+            "custom:Charity: United Way=25".parse().unwrap(),
+        ]);
+        assert_eq!(
+            posttax.get_posttax_deductions()[2].name(),
+            "Charity: United Way"
+        );
+        assert_eq!(
+            posttax.total_posttax_deductions(GrossPay::default()),
+            Money::new(95, 0)
+        );
+
+        assert!(
+            "custom:403(b):pre-tax=100"
+                .parse::<PreTaxDeduction>()
+                .is_err()
+        );
+        assert!("custom:=100".parse::<PostTaxDeduction>().is_err());
+    }
+
+    #[test]
+    fn test_custom_deduction_serde() {
+        let toml = r#"
+            pretax = [
+                { kind = "medical", amount = 150.0 },
+                { name = "Commuter", amount = 100.0 },
+                { name = "457(b)", amount = 300.0, treatment = "federal-only" },
+            ]
+            posttax = [{ name = "Union Dues", amount = 40.0 }]
+        "#;
+        let parsed: DeductionsTable = toml::from_str(toml).unwrap();
+        assert_eq!(
+            parsed
+                .pretax
+                .total_fica_exempt_deductions(GrossPay::default()),
+            Money::new(250, 0)
+        );
+        assert_eq!(
+            parsed.posttax.get_posttax_deductions()[0].name(),
+            "Union Dues"
+        );
+
+        let round_trip: DeductionsTable =
+            toml::from_str(&toml::to_string(&parsed).unwrap()).unwrap();
+        assert_eq!(
+            round_trip.pretax.get_pretax_deductions()[2].treatment(),
+            TaxTreatment::FederalOnly
+        );
+        assert_eq!(
+            round_trip
+                .posttax
+                .total_posttax_deductions(GrossPay::default()),
+            Money::new(40, 0)
+        );
+    }
+
+    #[test]
+    fn test_percentage_deductions() {
+        let pay = |regular, overtime| GrossPay {
+            regular: Money::new(regular, 0),
+            overtime: Money::new(overtime, 0),
+        };
+        let pretax = PreTaxDeductions::new(vec![
+            Medical(Some(Money::new(100, 0).into())),
+            "401k=6%".parse().unwrap(),
+            "custom:457(b):federal-only=2.5%regular".parse().unwrap(),
+        ]);
+        // 100 + 6% of 2,375 + 2.5% of 2,000
+        assert_eq!(
+            pretax.total_pretax_deductions(pay(2000, 375)),
+            Money::new(292, 50)
+        );
+        // fewer hours, smaller contributions
+        assert_eq!(
+            pretax.total_pretax_deductions(pay(1600, 0)),
+            Money::new(236, 0)
+        );
+        assert_eq!(
+            pretax.total_fica_exempt_deductions(pay(1600, 0)),
+            Money::new(100, 0)
+        );
+
+        let posttax = PostTaxDeductions::new(vec!["roth=4%gross".parse().unwrap()]);
+        assert_eq!(
+            posttax.total_posttax_deductions(pay(2000, 375)),
+            Money::new(95, 0)
+        );
+
+        assert_eq!(
+            "6.5%".parse::<DeductionAmount>(),
+            Ok(DeductionAmount::PercentOfGross(6.5))
+        );
+        assert_eq!(
+            "$200".parse::<DeductionAmount>(),
+            Ok(DeductionAmount::Flat(Money::new(200, 0)))
+        );
+        assert!("150%".parse::<DeductionAmount>().is_err());
+        assert!("6%overtime".parse::<DeductionAmount>().is_err());
+        assert!("401k=six%".parse::<PreTaxDeduction>().is_err());
+    }
+
+    #[test]
+    fn test_percentage_deduction_serde() {
+        let toml = r#"
+            pretax = [
+                { kind = "traditional401k", amount = { percent_of_gross = 6.0 } },
+                { name = "457(b)", amount = { percent_of_regular_pay = 2.5 }, treatment = "federal-only" },
+                { kind = "medical", amount = 100.0 },
+            ]
+            posttax = []
+        "#;
+        let parsed: DeductionsTable = toml::from_str(toml).unwrap();
+        let gross_pay = GrossPay {
+            regular: Money::new(2000, 0),
+            overtime: Money::new(375, 0),
+        };
+        assert_eq!(
+            parsed.pretax.total_pretax_deductions(gross_pay),
+            Money::new(292, 50)
+        );
+
+        let round_trip: DeductionsTable =
+            toml::from_str(&toml::to_string(&parsed).unwrap()).unwrap();
+        assert_eq!(
+            round_trip.pretax.total_pretax_deductions(gross_pay),
+            Money::new(292, 50)
+        );
+    }
+
+    #[test]
+    fn test_percentage_deduction_serde_rejects_out_of_range() {
+        for toml in [
+            r#"
+                pretax = [{ kind = "traditional401k", amount = { percent_of_gross = 250.0 } }]
+                posttax = []
+            "#,
+            r#"
+                pretax = []
+                posttax = [{ kind = "roth401k", amount = { percent_of_regular_pay = -50.0 } }]
+            "#,
+        ] {
+            assert!(toml::from_str::<DeductionsTable>(toml).is_err());
+        }
+    }
+
+    /// TOML needs a table at the top level, like a scenario file
+    #[derive(Serialize, Deserialize)]
+    struct DeductionsTable {
+        pretax: PreTaxDeductions,
+        posttax: PostTaxDeductions,
     }
 }
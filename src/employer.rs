@@ -0,0 +1,448 @@
+//! Module for the employer side of an employment scenario: the 401(k) match, the employer HSA contribution (seed) and employer-paid insurance premiums.
+//! None of these leave the employee's paycheck, but they are part of the pay when comparing job offers, so `EmploymentScenario::total_compensation` adds them to the gross wages of a full year.
+//!
+//! The 401(k) match follows the employee's traditional and Roth deferrals pay period by pay period, so a deferral below the rate needed for the full match, or deferrals that stop at the annual limit before the last paycheck, leave part of the match behind. `TotalCompensation::missed_match` reports how much.
+
+use crate::EmploymentScenario;
+use crate::contributions::ContributionPlan;
+use crate::money::Money;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// One tier of a 401(k) match formula
+/// # Fields
+/// * `match_percent` - percentage of the employee's deferral the employer matches, e.g. `100.0` for a dollar-for-dollar match
+/// * `up_to_percent` - percentage of pay up to which deferrals are matched at this rate. Tiers stack, so each tier matches the deferrals between the previous tier's `up_to_percent` and its own
+///
+/// "100% of the first 4%" is one tier `100%@4%`, "50% up to 6%" is `50%@6%`, and "100% of the first 3% and 50% of the next 2%" is two tiers `100%@3%,50%@5%`.
+/// Parses from `MATCH%@UP_TO%`, e.g. `50%@6%`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MatchTier {
+    pub match_percent: f64,
+    pub up_to_percent: f64,
+}
+
+impl FromStr for MatchTier {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((match_percent, up_to_percent)) = s.split_once('@') else {
+            return Err(format!(
+                "invalid match tier '{s}' (expected MATCH%@UP_TO%, e.g. 100%@4%)"
+            ));
+        };
+        let percent = |value: &str| {
+            value
+                .trim()
+                .trim_end_matches('%')
+                .trim()
+                .parse::<f64>()
+                .ok()
+        };
+        let match_percent = percent(match_percent)
+            .filter(|percent| *percent >= 0.0)
+            .ok_or_else(|| format!("invalid match percentage in '{s}'"))?;
+        let up_to_percent = percent(up_to_percent)
+            .filter(|percent| (0.0..=100.0).contains(percent))
+            .ok_or_else(|| format!("invalid percentage of pay in '{s}' (expected 0% to 100%)"))?;
+        Ok(MatchTier {
+            match_percent,
+            up_to_percent,
+        })
+    }
+}
+
+impl fmt::Display for MatchTier {
+    /// Formats as e.g. "50% up to 6% of pay"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}% up to {}% of pay",
+            self.match_percent, self.up_to_percent
+        )
+    }
+}
+
+/// Sorts the tiers of a match formula by `up_to_percent`, so tiers given in any order match the same
+/// # Errors
+/// Returns an error when two tiers have the same `up_to_percent`, since the formula would not say which rate applies
+/// # Example
+/// ```
+/// use paycheck_utils::{MatchTier, sort_match_tiers};
+///
+/// let tiers: Vec<MatchTier> = vec!["50%@5%".parse().unwrap(), "100%@3%".parse().unwrap()];
+/// let sorted = sort_match_tiers(tiers).unwrap();
+/// assert_eq!(sorted[0].up_to_percent, 3.0);
+/// assert!(sort_match_tiers(vec!["100%@4%".parse().unwrap(), "50%@4%".parse().unwrap()]).is_err());
+/// ```
+pub fn sort_match_tiers(mut tiers: Vec<MatchTier>) -> Result<Vec<MatchTier>, String> {
+    tiers.sort_by(|a, b| a.up_to_percent.total_cmp(&b.up_to_percent));
+    if let Some(pair) = tiers
+        .windows(2)
+        .find(|pair| pair[0].up_to_percent == pair[1].up_to_percent)
+    {
+        return Err(format!(
+            "match tiers {} and {} are both up to {}% of pay",
+            pair[0], pair[1], pair[0].up_to_percent
+        ));
+    }
+    Ok(tiers)
+}
+
+/// Deserializes the tiers of a match formula through `sort_match_tiers`
+fn deserialize_match_tiers<'de, D>(deserializer: D) -> Result<Vec<MatchTier>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    sort_match_tiers(Vec::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+}
+
+/// Contributions the employer makes on top of the employee's pay
+/// # Fields
+/// * `match_tiers` - the 401(k) match formula, matched each pay period against the paycheck's gross pay (no match when empty). Tiers apply in order of `up_to_percent`, and a scenario file with two tiers up to the same percentage is rejected
+/// * `hsa_seed` - employer HSA contribution for the year. It counts toward the HSA limit, so it lowers the limit on the employee's own HSA deductions
+/// * `premiums` - employer-paid share of insurance premiums per paycheck
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmployerContributions {
+    #[serde(deserialize_with = "deserialize_match_tiers")]
+    pub match_tiers: Vec<MatchTier>,
+    pub hsa_seed: Money,
+    pub premiums: Money,
+}
+
+impl EmployerContributions {
+    /// Whether the employer contributes nothing
+    pub fn is_empty(&self) -> bool {
+        self.match_tiers.is_empty() && self.hsa_seed.is_zero() && self.premiums.is_zero()
+    }
+
+    /// Percentage of pay the employee has to defer to receive the full match
+    pub fn full_match_percent(&self) -> f64 {
+        self.match_tiers
+            .iter()
+            .map(|tier| tier.up_to_percent)
+            .fold(0.0, f64::max)
+    }
+
+    /// Employer match of a paycheck's 401(k) deferral, with the tiers applied to the paycheck's gross pay in order of `up_to_percent`
+    /// # Example
+    /// ```
+    /// use paycheck_utils::{EmployerContributions, Money};
+    ///
+    /// // 100% of the first 3% of pay and 50% of the next 2%
+    /// let employer = EmployerContributions {
+    ///     match_tiers: vec!["100%@3%".parse().unwrap(), "50%@5%".parse().unwrap()],
+    ///     ..EmployerContributions::default()
+    /// };
+    /// let gross_pay = Money::new(2000, 0);
+    /// assert_eq!(employer.match_amount(Money::new(40, 0), gross_pay), Money::new(40, 0)); // 2% deferred
+    /// assert_eq!(employer.match_amount(Money::new(80, 0), gross_pay), Money::new(70, 0)); // 4% deferred
+    /// assert_eq!(employer.match_amount(Money::new(200, 0), gross_pay), Money::new(80, 0)); // 10% deferred
+    /// ```
+    pub fn match_amount(&self, deferral: Money, gross_pay: Money) -> Money {
+        let mut tiers = self.match_tiers.clone();
+        tiers.sort_by(|a, b| a.up_to_percent.total_cmp(&b.up_to_percent));
+
+        let mut matched = Money::ZERO;
+        let mut matched_up_to = Money::ZERO;
+        for tier in &tiers {
+            let up_to = gross_pay.multiply(tier.up_to_percent / 100.0);
+            let deferral_in_tier = (deferral.min(up_to) - matched_up_to).max(Money::ZERO);
+            matched += deferral_in_tier.multiply(tier.match_percent / 100.0);
+            matched_up_to = matched_up_to.max(up_to);
+        }
+        matched
+    }
+}
+
+/// Employer match the employee leaves behind during the year
+/// # Fields
+/// * `deferral` - the employee's elected 401(k) deferral per paycheck, traditional and Roth combined
+/// * `full_match_deferral` - deferral per paycheck needed for the full match (`full_match_percent` of gross pay)
+/// * `full_match_percent` - percentage of pay needed for the full match
+/// * `missed` - match not received over the year, because the deferral is too low or stops at the elective deferral limit before the last paycheck
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct MissedMatch {
+    pub deferral: Money,
+    pub full_match_deferral: Money,
+    pub full_match_percent: f64,
+    pub missed: Money,
+}
+
+impl fmt::Display for MissedMatch {
+    /// Formats as e.g. "contribute at least $118.75 per paycheck (5% of pay) to the 401(k) instead of $47.50 to receive the full employer match, $1235.00 of match is missed this year"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.deferral < self.full_match_deferral {
+            write!(
+                f,
+                "contribute at least ${} per paycheck ({}% of pay) to the 401(k) instead of ${} to receive the full employer match",
+                self.full_match_deferral, self.full_match_percent, self.deferral
+            )?;
+        } else {
+            write!(
+                f,
+                "401(k) contributions reach the elective deferral limit before the last paycheck and the match stops with them"
+            )?;
+        }
+        write!(f, ", ${} of match is missed this year", self.missed)
+    }
+}
+
+/// Annual compensation of a scenario, employer contributions included
+/// # Fields
+/// * `gross_wages` - gross pay of every paycheck in the year
+/// * `employer_match` - 401(k) match received over the year
+/// * `employer_hsa` - employer HSA contribution
+/// * `employer_premiums` - employer-paid premiums over the year
+/// * `total` - gross wages plus every employer contribution
+/// * `missed_match` - employer match left behind, if any
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct TotalCompensation {
+    pub gross_wages: Money,
+    pub employer_match: Money,
+    pub employer_hsa: Money,
+    pub employer_premiums: Money,
+    pub total: Money,
+    pub missed_match: Option<MissedMatch>,
+}
+
+impl TotalCompensation {
+    /// Total of the employer contributions
+    pub fn employer_contributions(&self) -> Money {
+        self.employer_match + self.employer_hsa + self.employer_premiums
+    }
+}
+
+impl EmploymentScenario {
+    /// Annual compensation of a full year of paychecks, with the 401(k) match of each pay period's deferral, the employer HSA contribution and the employer-paid premiums.
+    /// Deferrals follow `contribution_schedule`, so the match stops when the deferrals reach the elective deferral limit.
+    ///
+    /// # Example
+    /// ```
+    /// use paycheck_utils::*;
+    ///
+    /// // $25/hour for 40 hours with 2% of pay deferred, and a match of 100% of the first 4%
+    /// let mut scenario = EmploymentScenario::new(
+    ///     Money::new(25, 0),
+    ///     40.0,
+    ///     FilingStatus::Single,
+    ///     PreTaxDeductions::new(vec![PreTaxDeduction::Traditional401K(Some(DeductionAmount::PercentOfGross(2.0)))]),
+    ///     PostTaxDeductions::default(),
+    ///     Expenses::default(),
+    /// );
+    /// scenario.employer = EmployerContributions {
+    ///     match_tiers: vec!["100%@4%".parse().unwrap()],
+    ///     hsa_seed: Money::new(500, 0),
+    ///     premiums: Money::new(200, 0),
+    /// };
+    ///
+    /// let compensation = scenario.total_compensation();
+    /// assert_eq!(compensation.gross_wages, Money::new(52_000, 0));
+    /// assert_eq!(compensation.employer_match, Money::new(1040, 0));
+    /// assert_eq!(compensation.total, Money::new(58_740, 0));
+    ///
+    /// let missed_match = compensation.missed_match.unwrap();
+    /// assert_eq!(missed_match.full_match_deferral, Money::new(80, 0));
+    /// assert_eq!(missed_match.missed, Money::new(1040, 0));
+    /// ```
+    pub fn total_compensation(&self) -> TotalCompensation {
+        let gross_pay = self.pay_basis.gross_pay(self.pay_frequency).total();
+        let periods = self.contribution_schedule(None).periods;
+        let full_match_percent = self.employer.full_match_percent();
+        let full_match_deferral = gross_pay.multiply(full_match_percent / 100.0);
+        let full_match = self.employer.match_amount(full_match_deferral, gross_pay);
+
+        let mut employer_match = Money::ZERO;
+        let mut missed = Money::ZERO;
+        for period in &periods {
+            let matched = self.employer.match_amount(
+                period.contributed.get(ContributionPlan::ElectiveDeferral),
+                gross_pay,
+            );
+            employer_match += matched;
+            missed += full_match - matched;
+        }
+
+        let gross_wages = gross_pay.multiply(periods.len() as f64);
+        let employer_premiums = self.employer.premiums.multiply(periods.len() as f64);
+        let deferral = periods
+            .first()
+            .map(|period| period.elected.get(ContributionPlan::ElectiveDeferral))
+            .unwrap_or_default();
+        TotalCompensation {
+            gross_wages,
+            employer_match,
+            employer_hsa: self.employer.hsa_seed,
+            employer_premiums,
+            total: gross_wages + employer_match + self.employer.hsa_seed + employer_premiums,
+            missed_match: (missed > Money::ZERO).then_some(MissedMatch {
+                deferral,
+                full_match_deferral,
+                full_match_percent,
+                missed,
+            }),
+        }
+    }
+}
+
+// UNIT TESTS FOR EMPLOYER MODULE
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::ScenarioBuilder;
+    use crate::*;
+
+    fn employer(tiers: &[&str]) -> EmployerContributions {
+        EmployerContributions {
+            match_tiers: tiers.iter().map(|tier| tier.parse().unwrap()).collect(),
+            ..EmployerContributions::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_match_tier() {
+        assert_eq!(
+            "100%@4%".parse::<MatchTier>(),
+            Ok(MatchTier {
+                match_percent: 100.0,
+                up_to_percent: 4.0
+            })
+        );
+        assert_eq!(
+            " 50 @ 6 ".parse::<MatchTier>().unwrap().to_string(),
+            "50% up to 6% of pay"
+        );
+        assert!("100%".parse::<MatchTier>().is_err());
+        assert!("100%@150%".parse::<MatchTier>().is_err());
+        assert!("-50%@6%".parse::<MatchTier>().is_err());
+    }
+
+    #[test]
+    fn test_match_formulas() {
+        let gross_pay = Money::new(4000, 0);
+
+        let dollar_for_dollar = employer(&["100%@4%"]);
+        assert_eq!(dollar_for_dollar.full_match_percent(), 4.0);
+        assert_eq!(
+            dollar_for_dollar.match_amount(Money::new(100, 0), gross_pay),
+            Money::new(100, 0)
+        );
+        assert_eq!(
+            dollar_for_dollar.match_amount(Money::new(400, 0), gross_pay),
+            Money::new(160, 0)
+        );
+
+        let half = employer(&["50%@6%"]);
+        assert_eq!(
+            half.match_amount(Money::new(240, 0), gross_pay),
+            Money::new(120, 0)
+        );
+        assert_eq!(
+            half.match_amount(Money::new(1000, 0), gross_pay),
+            Money::new(120, 0)
+        );
+
+        // the tiers match the same in either order
+        let tiered = employer(&["100%@3%", "50%@5%"]);
+        let reversed = employer(&["50%@5%", "100%@3%"]);
+        for deferral in [80, 160, 400] {
+            assert_eq!(
+                reversed.match_amount(Money::new(deferral, 0), gross_pay),
+                tiered.match_amount(Money::new(deferral, 0), gross_pay)
+            );
+        }
+        assert_eq!(
+            reversed.match_amount(Money::new(400, 0), gross_pay),
+            Money::new(160, 0)
+        );
+
+        assert_eq!(EmployerContributions::default().full_match_percent(), 0.0);
+        assert_eq!(
+            EmployerContributions::default().match_amount(Money::new(400, 0), gross_pay),
+            Money::ZERO
+        );
+    }
+
+    #[test]
+    fn test_sort_match_tiers() {
+        let sorted = sort_match_tiers(employer(&["50%@5%", "100%@3%"]).match_tiers).unwrap();
+        assert_eq!(sorted, employer(&["100%@3%", "50%@5%"]).match_tiers);
+        assert!(sort_match_tiers(employer(&["100%@4%", "50%@4%"]).match_tiers).is_err());
+
+        let parsed: EmployerContributions = toml::from_str(
+            r#"match_tiers = [{ match_percent = 50.0, up_to_percent = 5.0 }, { match_percent = 100.0, up_to_percent = 3.0 }]"#,
+        )
+        .unwrap();
+        assert_eq!(parsed.match_tiers, sorted);
+        assert!(
+            toml::from_str::<EmployerContributions>(
+                r#"match_tiers = [{ match_percent = 100.0, up_to_percent = 4.0 }, { match_percent = 50.0, up_to_percent = 4.0 }]"#,
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_full_match_received() {
+        let mut scenario = ScenarioBuilder::hourly(50, 40.0)
+            .pretax(vec![PreTaxDeduction::Traditional401K(Some(
+                DeductionAmount::PercentOfGross(6.0),
+            ))])
+            .build();
+        scenario.employer = employer(&["50%@6%"]);
+        let compensation = scenario.total_compensation();
+        assert_eq!(compensation.gross_wages, Money::new(104_000, 0));
+        assert_eq!(compensation.employer_match, Money::new(3120, 0));
+        assert_eq!(compensation.missed_match, None);
+        assert_eq!(compensation.total, Money::new(107_120, 0));
+    }
+
+    #[test]
+    fn test_match_stops_at_the_elective_deferral_limit() {
+        // $1,000 a paycheck reaches the $24,500 limit in pay period 25 and nothing is deferred in pay period 26
+        let mut scenario = ScenarioBuilder::hourly(50, 40.0)
+            .pretax(vec![PreTaxDeduction::Traditional401K(Some(
+                Money::new(1000, 0).into(),
+            ))])
+            .build();
+        scenario.employer = employer(&["100%@4%"]);
+        let compensation = scenario.total_compensation();
+        assert_eq!(compensation.employer_match, Money::new(4000, 0));
+
+        let missed_match = compensation.missed_match.unwrap();
+        assert_eq!(missed_match.missed, Money::new(160, 0));
+        assert!(
+            missed_match
+                .to_string()
+                .starts_with("401(k) contributions reach the elective deferral limit")
+        );
+    }
+
+    #[test]
+    fn test_hsa_seed_counts_toward_the_hsa_limit() {
+        let mut scenario = ScenarioBuilder::hourly(50, 40.0)
+            .pretax(vec![PreTaxDeduction::Traditional401K(Some(
+                Money::ZERO.into(),
+            ))])
+            .build();
+        scenario.employer.hsa_seed = Money::new(1000, 0);
+        scenario.employer.premiums = Money::new(250, 0);
+        assert_eq!(
+            scenario.contribution_limit(ContributionPlan::Hsa),
+            Money::new(3400, 0)
+        );
+        assert_eq!(
+            scenario.contribution_limit(ContributionPlan::ElectiveDeferral),
+            Money::new(24_500, 0)
+        );
+
+        let compensation = scenario.total_compensation();
+        assert_eq!(compensation.employer_hsa, Money::new(1000, 0));
+        assert_eq!(compensation.employer_premiums, Money::new(6500, 0));
+        assert_eq!(compensation.employer_contributions(), Money::new(7500, 0));
+        assert_eq!(compensation.missed_match, None);
+    }
+}
@@ -1,89 +1,345 @@
 //! Module for handling expense calculations.
-//! Defines common standard expense categories and functions for totaling monthly expenses.
+//! Defines common standard expense categories, user-named custom expenses with an optional group, how often each expense is paid, and functions for totaling expenses per month, per paycheck and per year.
+
+use crate::constants::{MONTHS_PER_YEAR, PayFrequency};
+use crate::money::Money;
+use crate::utils::{parse_custom_amount, parse_kind_amount};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 /// Common expense categories for monthly expenses.
-/// Each variant can hold an optional f32 value representing the monthly expense amount.
+/// Each variant can hold an optional `Money` value representing the expense amount, paid monthly unless the `ExpenseItem` holding it has another `ExpenseFrequency`.
 /// If no amount is provided, it is treated as zero in calculations.
 /// # Variants
-/// * `Housing(Option<f32>)` - Monthly rent or mortgage expense
-/// * `Energy(Option<f32>)` - Monthly energy expense
-/// * `Water(Option<f32>)` - Monthly water expense
-/// * `Gas(Option<f32>)` - Monthly gas expense
-/// * `Internet(Option<f32>)` - Monthly internet expense
-/// * `Phone(Option<f32>)` - Monthly phone expense
-/// * `Vehicle(Option<f32>)` - Monthly vehicle expense
-/// * `VehicleInsurance(Option<f32>)` - Monthly vehicle insurance expense
-/// * `VehicleGas(Option<f32>)` - Monthly vehicle gas expense
-/// * `Groceries(Option<f32>)` - Monthly groceries expense
-#[derive(Debug)]
+/// * `Housing(Option<Money>)` - Monthly rent or mortgage expense
+/// * `Energy(Option<Money>)` - Monthly energy expense
+/// * `Water(Option<Money>)` - Monthly water expense
+/// * `Gas(Option<Money>)` - Monthly gas expense
+/// * `Internet(Option<Money>)` - Monthly internet expense
+/// * `Phone(Option<Money>)` - Monthly phone expense
+/// * `Vehicle(Option<Money>)` - Monthly vehicle expense
+/// * `VehicleInsurance(Option<Money>)` - Monthly vehicle insurance expense
+/// * `VehicleGas(Option<Money>)` - Monthly vehicle gas expense
+/// * `Groceries(Option<Money>)` - Monthly groceries expense
+/// * `Custom { name, amount, group }` - Monthly expense named by the user (e.g. childcare, student loans, subscriptions), with an optional group to total it with similar expenses
+///
+/// The standard categories are presets with a fixed name and group (see `name` and `group`).
+/// In a scenario file a custom expense is written without a kind, e.g. `{ name = "Childcare", amount = 800, group = "Family" }`.
+///
+/// Parses from `KIND=AMOUNT` text (e.g. `housing=1500`), where the kind is one of housing, energy, water, gas, internet, phone, vehicle (or car-payment), vehicle-insurance (or car-insurance), vehicle-gas (or car-gas) or groceries,
+/// or from `custom:NAME=AMOUNT` or `custom:NAME:GROUP=AMOUNT` text for a custom expense (e.g. `custom:Childcare:Family=800`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "amount", rename_all = "lowercase")]
 pub enum Expense {
-    Housing(Option<f32>),          // monthly rent or mortgage expense
-    Energy(Option<f32>),           // monthly energy expense
-    Water(Option<f32>),            // monthly water expense
-    Gas(Option<f32>),              // monthly gas expense
-    Internet(Option<f32>),         // monthly internet expense
-    Phone(Option<f32>),            // monthly phone expense
-    Vehicle(Option<f32>),          // monthly vehicle expense
-    VehicleInsurance(Option<f32>), // monthly insurance expense
-    VehicleGas(Option<f32>),       // monthly vehicle gas expense
-    Groceries(Option<f32>),        // monthly groceries expense
+    Housing(Option<Money>),          // monthly rent or mortgage expense
+    Energy(Option<Money>),           // monthly energy expense
+    Water(Option<Money>),            // monthly water expense
+    Gas(Option<Money>),              // monthly gas expense
+    Internet(Option<Money>),         // monthly internet expense
+    Phone(Option<Money>),            // monthly phone expense
+    Vehicle(Option<Money>),          // monthly vehicle expense
+    VehicleInsurance(Option<Money>), // monthly insurance expense
+    VehicleGas(Option<Money>),       // monthly vehicle gas expense
+    Groceries(Option<Money>),        // monthly groceries expense
+    #[serde(untagged)]
+    Custom {
+        name: String,
+        amount: Option<Money>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        group: Option<String>,
+    }, // monthly expense named by the user
+}
+
+impl Expense {
+    /// Name of the expense, e.g. "Housing" or the name of a custom expense
+    pub fn name(&self) -> &str {
+        match self {
+            Expense::Housing(_) => "Housing",
+            Expense::Energy(_) => "Energy",
+            Expense::Water(_) => "Water",
+            Expense::Gas(_) => "Gas",
+            Expense::Internet(_) => "Internet",
+            Expense::Phone(_) => "Phone",
+            Expense::Vehicle(_) => "Car Payment",
+            Expense::VehicleInsurance(_) => "Car Insurance",
+            Expense::VehicleGas(_) => "Car Gas",
+            Expense::Groceries(_) => "Groceries",
+            Expense::Custom { name, .. } => name,
+        }
+    }
+
+    /// Group the expense is totaled with: "Housing", "Utilities", "Transportation" or "Food" for the standard categories, or the group of a custom expense (if any)
+    pub fn group(&self) -> Option<&str> {
+        match self {
+            Expense::Housing(_) => Some("Housing"),
+            Expense::Energy(_)
+            | Expense::Water(_)
+            | Expense::Gas(_)
+            | Expense::Internet(_)
+            | Expense::Phone(_) => Some("Utilities"),
+            Expense::Vehicle(_) | Expense::VehicleInsurance(_) | Expense::VehicleGas(_) => {
+                Some("Transportation")
+            }
+            Expense::Groceries(_) => Some("Food"),
+            Expense::Custom { group, .. } => group.as_deref(),
+        }
+    }
+
+    /// Monthly amount of the expense, zero when no amount is provided
+    pub fn amount(&self) -> Money {
+        match self {
+            Expense::Housing(amount)
+            | Expense::Energy(amount)
+            | Expense::Water(amount)
+            | Expense::Gas(amount)
+            | Expense::Internet(amount)
+            | Expense::Phone(amount)
+            | Expense::Vehicle(amount)
+            | Expense::VehicleInsurance(amount)
+            | Expense::VehicleGas(amount)
+            | Expense::Groceries(amount)
+            | Expense::Custom { amount, .. } => amount.unwrap_or_default(),
+        }
+    }
+}
+
+impl FromStr for Expense {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(custom) = parse_custom_amount(s, true) {
+            let (name, group, amount) = custom?;
+            return Ok(Expense::Custom {
+                name,
+                amount: Some(amount),
+                group,
+            });
+        }
+
+        let (kind, amount) = parse_kind_amount(s)?;
+        let amount = Some(amount);
+        match kind.as_str() {
+            "housing" | "rent" | "mortgage" => Ok(Expense::Housing(amount)),
+            "energy" | "electric" => Ok(Expense::Energy(amount)),
+            "water" => Ok(Expense::Water(amount)),
+            "gas" => Ok(Expense::Gas(amount)),
+            "internet" => Ok(Expense::Internet(amount)),
+            "phone" => Ok(Expense::Phone(amount)),
+            "vehicle" | "carpayment" => Ok(Expense::Vehicle(amount)),
+            "vehicleinsurance" | "carinsurance" => Ok(Expense::VehicleInsurance(amount)),
+            "vehiclegas" | "cargas" => Ok(Expense::VehicleGas(amount)),
+            "groceries" => Ok(Expense::Groceries(amount)),
+            _ => Err(format!(
+                "unknown expense in '{s}' (expected housing, energy, water, gas, internet, phone, vehicle, vehicle-insurance, vehicle-gas, groceries or custom:NAME)"
+            )),
+        }
+    }
+}
+
+/// How often an expense is paid
+/// # Variants
+/// * `Weekly` - 52 times a year (e.g. groceries)
+/// * `BiWeekly` - 26 times a year
+/// * `Monthly` - 12 times a year (the default)
+/// * `Quarterly` - 4 times a year
+/// * `SemiAnnual` - twice a year (e.g. vehicle insurance)
+/// * `Annual` - once a year (e.g. vehicle registration)
+/// * `OneTime` - once, counted in the year it is paid (e.g. a move)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ExpenseFrequency {
+    Weekly,
+    BiWeekly,
+    #[default]
+    Monthly,
+    Quarterly,
+    SemiAnnual,
+    Annual,
+    OneTime,
+}
+
+impl ExpenseFrequency {
+    /// Number of times the expense is paid in a year (52 weekly, 26 bi-weekly, 12 monthly, 4 quarterly, 2 semi-annual, 1 annual or one-time)
+    pub fn times_per_year(&self) -> u32 {
+        match self {
+            ExpenseFrequency::Weekly => 52,
+            ExpenseFrequency::BiWeekly => 26,
+            ExpenseFrequency::Monthly => 12,
+            ExpenseFrequency::Quarterly => 4,
+            ExpenseFrequency::SemiAnnual => 2,
+            ExpenseFrequency::Annual | ExpenseFrequency::OneTime => 1,
+        }
+    }
+}
+
+impl FromStr for ExpenseFrequency {
+    type Err = String;
+
+    /// Parses "weekly", "biweekly", "monthly", "quarterly", "semiannual", "annual" (or "yearly") or "onetime" (or "once"), case insensitive, with or without a hyphen
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace('-', "").as_str() {
+            "weekly" => Ok(ExpenseFrequency::Weekly),
+            "biweekly" => Ok(ExpenseFrequency::BiWeekly),
+            "monthly" => Ok(ExpenseFrequency::Monthly),
+            "quarterly" => Ok(ExpenseFrequency::Quarterly),
+            "semiannual" => Ok(ExpenseFrequency::SemiAnnual),
+            "annual" | "yearly" => Ok(ExpenseFrequency::Annual),
+            "onetime" | "once" => Ok(ExpenseFrequency::OneTime),
+            other => Err(format!(
+                "unknown expense frequency '{other}' (expected weekly, biweekly, monthly, quarterly, semiannual, annual or onetime)"
+            )),
+        }
+    }
+}
+
+/// An expense and how often it is paid
+/// # Fields
+/// * `expense` - the expense and the amount of each payment
+/// * `frequency` - how often the expense is paid (monthly when left out of a scenario file)
+///
+/// In a scenario file the frequency is written next to the expense, e.g. `{ kind = "groceries", amount = 120, frequency = "weekly" }`.
+/// Parses from the `Expense` text with an optional `/FREQUENCY` suffix (e.g. `groceries=120/weekly` or `custom:Registration=180/annual`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExpenseItem {
+    #[serde(flatten)]
+    pub expense: Expense,
+    #[serde(default)]
+    pub frequency: ExpenseFrequency,
+}
+
+impl ExpenseItem {
+    pub fn new(expense: Expense, frequency: ExpenseFrequency) -> Self {
+        ExpenseItem { expense, frequency }
+    }
+
+    /// Total of the expense over a year (the amount times the payments in a year)
+    pub fn annual_amount(&self) -> Money {
+        self.expense
+            .amount()
+            .multiply(self.frequency.times_per_year())
+    }
+
+    /// Average amount of the expense per month (the annual amount / 12)
+    pub fn monthly_amount(&self) -> Money {
+        self.annual_amount().divide(MONTHS_PER_YEAR)
+    }
+}
+
+impl From<Expense> for ExpenseItem {
+    /// A monthly expense
+    fn from(expense: Expense) -> Self {
+        ExpenseItem::new(expense, ExpenseFrequency::Monthly)
+    }
 }
 
-/// Struct to hold a collection of monthly expenses.
-/// Provides functionality to total all monthly expenses.
+impl FromStr for ExpenseItem {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // the frequency follows the amount, after the last '='
+        let (expense, frequency) = match s.rsplit_once('/') {
+            Some((expense, frequency)) if expense.contains('=') && !frequency.contains('=') => {
+                (expense, frequency.parse::<ExpenseFrequency>()?)
+            }
+            _ => (s, ExpenseFrequency::Monthly),
+        };
+        Ok(ExpenseItem::new(expense.parse::<Expense>()?, frequency))
+    }
+}
+
+/// Struct to hold a collection of expenses.
+/// Provides functionality to total all expenses per month, per paycheck and per year, normalizing each expense by how often it is paid.
 /// # Fields
-/// * `expense_items: Vec<Expense>` - Vector of Expense enum variants representing different monthly expenses.
+/// * `expense_items: Vec<ExpenseItem>` - Vector of expenses and how often each is paid.
 /// # Methods
-/// * `new(expenses: Vec<Expense>) -> Self` - Creates a new Expenses struct from a vector of Expense items.
-/// * `total_monthly_expenses(&self) -> f32` - Calculates the total of all monthly expenses, treating None values as zero.
+/// * `new(expenses: Vec<Expense>) -> Self` - Creates a new Expenses struct from a vector of monthly Expense items.
+/// * `from_items(expense_items: Vec<ExpenseItem>) -> Self` - Creates a new Expenses struct from expenses with any frequency.
+/// * `total_annual_expenses(&self) -> Money` - Calculates the total of all expenses over a year, treating None values as zero.
+/// * `total_monthly_expenses(&self) -> Money` - Calculates the average total of all expenses per month (the annual total / 12).
+/// * `total_expenses_per_paycheck(&self, pay_frequency: PayFrequency) -> Money` - Calculates the average total of all expenses per paycheck (the annual total / pay periods in a year).
+/// * `total_monthly_expenses_by_group(&self) -> Vec<(String, Money)>` - Calculates the average total monthly expenses of each expense group.
 /// # Example
 /// ```
-/// use paycheck_utils::expenses::{Expense, Expenses};
+/// use paycheck_utils::expenses::{Expense, ExpenseFrequency, ExpenseItem, Expenses};
+/// use paycheck_utils::{Money, PayFrequency};
 ///
 /// let expenses = Expenses::new(vec![
-///     Expense::Housing(Some(2000.0)),
-///     Expense::Energy(Some(150.0)),
+///     Expense::Housing(Some(Money::new(2000, 0))),
+///     Expense::Energy(Some(Money::new(150, 0))),
 ///     Expense::Water(None),
-///     Expense::Internet(Some(60.0)),
-///     Expense::Phone(Some(80.0)),
-///     Expense::Vehicle(Some(300.0)),
-///     Expense::VehicleInsurance(Some(100.0)),
-///     Expense::VehicleGas(Some(120.0)),
-///     Expense::Groceries(Some(400.0)),
+///     Expense::Internet(Some(Money::new(60, 0))),
+///     Expense::Phone(Some(Money::new(80, 0))),
+///     Expense::Vehicle(Some(Money::new(300, 0))),
+///     Expense::VehicleInsurance(Some(Money::new(100, 0))),
+///     Expense::VehicleGas(Some(Money::new(120, 0))),
+///     Expense::Groceries(Some(Money::new(400, 0))),
+///     Expense::Custom {
+///         name: "Childcare".to_string(),
+///         amount: Some(Money::new(800, 0)),
+///         group: Some("Family".to_string()),
+///     },
 /// ]);
 /// let total = expenses.total_monthly_expenses();
-/// assert_eq!(total, 3210.0);
+/// assert_eq!(total, Money::new(4010, 0));
+/// assert_eq!(expenses.total_monthly_expenses_by_group()[1], ("Utilities".to_string(), Money::new(290, 0)));
+///
+/// let expenses = Expenses::from_items(vec![
+///     ExpenseItem::new(Expense::Housing(Some(Money::new(1500, 0))), ExpenseFrequency::Monthly),
+///     ExpenseItem::new(Expense::Groceries(Some(Money::new(100, 0))), ExpenseFrequency::Weekly),
+///     ExpenseItem::new(Expense::VehicleInsurance(Some(Money::new(600, 0))), ExpenseFrequency::SemiAnnual),
+/// ]);
+/// assert_eq!(expenses.total_annual_expenses(), Money::new(24_400, 0)); // 18,000 + 5,200 + 1,200
+/// assert_eq!(expenses.total_monthly_expenses(), Money::new(2033, 33));
+/// assert_eq!(expenses.total_expenses_per_paycheck(PayFrequency::BiWeekly), Money::new(938, 46));
 /// ```
-
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct Expenses {
-    pub expense_items: Vec<Expense>,
+    pub expense_items: Vec<ExpenseItem>,
 }
 
 impl Expenses {
     pub fn new(expenses: Vec<Expense>) -> Self {
         Expenses {
-            expense_items: expenses,
+            expense_items: expenses.into_iter().map(ExpenseItem::from).collect(),
         }
     }
 
-    pub fn total_monthly_expenses(&self) -> f32 {
+    pub fn from_items(expense_items: Vec<ExpenseItem>) -> Self {
+        Expenses { expense_items }
+    }
+
+    pub fn total_annual_expenses(&self) -> Money {
         self.expense_items
             .iter()
-            .map(|expense| match expense {
-                Expense::Housing(amount)
-                | Expense::Energy(amount)
-                | Expense::Water(amount)
-                | Expense::Gas(amount)
-                | Expense::Internet(amount)
-                | Expense::Phone(amount)
-                | Expense::Vehicle(amount)
-                | Expense::VehicleInsurance(amount)
-                | Expense::VehicleGas(amount)
-                | Expense::Groceries(amount) => amount.unwrap_or(0.0),
-            })
+            .map(ExpenseItem::annual_amount)
             .sum()
     }
+
+    pub fn total_monthly_expenses(&self) -> Money {
+        self.total_annual_expenses().divide(MONTHS_PER_YEAR)
+    }
+
+    pub fn total_expenses_per_paycheck(&self, pay_frequency: PayFrequency) -> Money {
+        self.total_annual_expenses()
+            .divide(pay_frequency.periods_per_year())
+    }
+
+    /// Average total monthly expenses of each group, in the order each group first appears, with the expenses without a group totaled as "Other"
+    pub fn total_monthly_expenses_by_group(&self) -> Vec<(String, Money)> {
+        let mut totals: Vec<(String, Money)> = Vec::new();
+        for item in &self.expense_items {
+            let group = item.expense.group().unwrap_or("Other");
+            match totals.iter_mut().find(|(name, _)| name == group) {
+                Some((_, total)) => *total += item.annual_amount(),
+                None => totals.push((group.to_string(), item.annual_amount())),
+            }
+        }
+        totals
+            .into_iter()
+            .map(|(group, annual)| (group, annual.divide(MONTHS_PER_YEAR)))
+            .collect()
+    }
 }
 
 // UNIT TESTS FOR EXPENSES MODULE
@@ -93,21 +349,148 @@ mod tests {
     use super::*;
     #[test]
     fn test_total_monthly_expenses() {
-        let expenses = Expenses {
-            expense_items: vec![
-                Expense::Housing(Some(2200.0)),
-                Expense::Energy(Some(250.0)),
-                Expense::Water(Some(50.0)),
-                Expense::Gas(None),
-                Expense::Internet(Some(60.0)),
-                Expense::Phone(Some(80.0)),
-                Expense::Vehicle(Some(300.0)),
-                Expense::VehicleInsurance(Some(150.0)),
-                Expense::VehicleGas(Some(120.0)),
-                Expense::Groceries(Some(400.0)),
-            ],
-        };
+        let expenses = Expenses::new(vec![
+            Expense::Housing(Some(Money::new(2200, 0))),
+            Expense::Energy(Some(Money::new(250, 0))),
+            Expense::Water(Some(Money::new(50, 0))),
+            Expense::Gas(None),
+            Expense::Internet(Some(Money::new(60, 0))),
+            Expense::Phone(Some(Money::new(80, 0))),
+            Expense::Vehicle(Some(Money::new(300, 0))),
+            Expense::VehicleInsurance(Some(Money::new(150, 0))),
+            Expense::VehicleGas(Some(Money::new(120, 0))),
+            Expense::Groceries(Some(Money::new(400, 0))),
+        ]);
         let total = expenses.total_monthly_expenses();
-        assert_eq!(total, 3610.0);
+        assert_eq!(total, Money::new(3610, 0));
+    }
+
+    #[test]
+    fn test_parse_expense_from_kind_amount() {
+        let expenses = Expenses::new(vec![
+            "housing=1500".parse().unwrap(),
+            "Car-Payment=$350.25".parse().unwrap(),
+        ]);
+        assert!(matches!(
+            expenses.expense_items[1].expense,
+            Expense::Vehicle(_)
+        ));
+        assert_eq!(expenses.total_monthly_expenses(), Money::new(1850, 25));
+        assert!("boat=100".parse::<Expense>().is_err());
+    }
+
+    #[test]
+    fn test_custom_expenses() {
+        let expenses = Expenses::new(vec![
+            Expense::Housing(Some(Money::new(1500, 0))),
+            "custom:Childcare:Family=800".parse().unwrap(),
+            "CUSTOM:Streaming=$25.99".parse().unwrap(),
+            "custom:Pet Food:Family=60".parse().unwrap(),
+        ]);
+        assert_eq!(
+            expenses.expense_items[1].expense,
+            Expense::Custom {
+                name: "Childcare".to_string(),
+                amount: Some(Money::new(800, 0)),
+                group: Some("Family".to_string()),
+            }
+        );
+        assert_eq!(expenses.expense_items[2].expense.group(), None);
+        assert_eq!(expenses.total_monthly_expenses(), Money::new(2385, 99));
+        assert_eq!(
+            expenses.total_monthly_expenses_by_group(),
+            vec![
+                ("Housing".to_string(), Money::new(1500, 0)),
+                ("Family".to_string(), Money::new(860, 0)),
+                ("Other".to_string(), Money::new(25, 99)),
+            ]
+        );
+        assert!("custom:=100".parse::<Expense>().is_err());
+        assert!("custom:Childcare".parse::<Expense>().is_err());
+    }
+
+    #[test]
+    fn test_custom_expense_serde() {
+        let expenses = Expenses::new(vec![
+            Expense::Housing(Some(Money::new(1500, 0))),
+            "custom:Childcare:Family=800".parse().unwrap(),
+            "custom:Streaming=25".parse().unwrap(),
+        ]);
+        let toml = toml::to_string(&ExpensesTable { expenses }).unwrap();
+        assert!(toml.contains("name = \"Childcare\""), "{toml}");
+        let parsed: ExpensesTable = toml::from_str(&toml).unwrap();
+        assert_eq!(parsed.expenses.expense_items[1].expense.name(), "Childcare");
+        assert_eq!(parsed.expenses.expense_items[2].expense.group(), None);
+        assert_eq!(
+            parsed.expenses.total_monthly_expenses(),
+            Money::new(2325, 0)
+        );
+    }
+
+    #[test]
+    fn test_expense_frequencies() {
+        let expenses = Expenses::from_items(vec![
+            "housing=1500".parse().unwrap(),
+            "groceries=120/weekly".parse().unwrap(),
+            "vehicle-insurance=600/semi-annual".parse().unwrap(),
+            "custom:Registration:Transportation=180/annual"
+                .parse()
+                .unwrap(),
+            "custom:Moving=1200/once".parse().unwrap(),
+        ]);
+        assert_eq!(
+            expenses.expense_items[0].frequency,
+            ExpenseFrequency::Monthly
+        );
+        assert_eq!(
+            expenses.expense_items[1].monthly_amount(),
+            Money::new(520, 0)
+        );
+        // 18,000 + 6,240 + 1,200 + 180 + 1,200
+        assert_eq!(expenses.total_annual_expenses(), Money::new(26_820, 0));
+        assert_eq!(expenses.total_monthly_expenses(), Money::new(2235, 0));
+        assert_eq!(
+            expenses.total_expenses_per_paycheck(PayFrequency::SemiMonthly),
+            Money::new(1117, 50)
+        );
+        assert_eq!(
+            expenses.total_monthly_expenses_by_group()[2],
+            ("Transportation".to_string(), Money::new(115, 0))
+        );
+        assert!("groceries=120/daily".parse::<ExpenseItem>().is_err());
+    }
+
+    #[test]
+    fn test_expense_frequency_serde() {
+        let toml = r#"
+            [[expenses]]
+            kind = "housing"
+            amount = 1500.0
+
+            [[expenses]]
+            kind = "groceries"
+            amount = 100.0
+            frequency = "weekly"
+
+            [[expenses]]
+            name = "Registration"
+            amount = 180.0
+            frequency = "annual"
+        "#;
+        let parsed: ExpensesTable = toml::from_str(toml).unwrap();
+        let items = &parsed.expenses.expense_items;
+        assert_eq!(items[0].frequency, ExpenseFrequency::Monthly);
+        assert_eq!(items[1].frequency, ExpenseFrequency::Weekly);
+        assert_eq!(items[2].expense.name(), "Registration");
+        assert_eq!(items[2].frequency, ExpenseFrequency::Annual);
+
+        let round_trip: ExpensesTable = toml::from_str(&toml::to_string(&parsed).unwrap()).unwrap();
+        assert_eq!(&round_trip.expenses.expense_items, items);
+    }
+
+    /// TOML needs a table at the top level, like the `expenses` key of a scenario file
+    #[derive(Serialize, Deserialize)]
+    struct ExpensesTable {
+        expenses: Expenses,
     }
 }
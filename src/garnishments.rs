@@ -0,0 +1,310 @@
+//! Module for garnishment orders withheld from a paycheck.
+//! The Consumer Credit Protection Act (CCPA) limits garnishments to a share of the employee's disposable earnings: gross pay less the taxes the law requires to be withheld (federal income tax, Social Security and Medicare). Voluntary deductions such as health insurance or 401(k) deferrals do not lower disposable earnings.
+//!
+//! Orders are withheld in priority order (see `GarnishmentKind`), and each order only gets what is left of its limit after the orders ahead of it:
+//! * child support (and alimony): 50% of disposable earnings for an employee supporting a second family, 60% otherwise, plus 5% when the support is more than 12 weeks in arrears (see `SupportOrder`)
+//! * federal tax levy: not limited by the CCPA, it takes the pay left over an exempt amount, the standard deduction of the filing status spread over the pay periods (IRS Publication 1494, without the amount for dependents). The orders after a levy never take the pay left below the exempt amount either
+//! * student loan: 15% of disposable earnings
+//! * creditor: the lesser of 25% of disposable earnings or the disposable earnings over 30 times the federal minimum wage per week. Support, student loan and creditor garnishments together never take more than this limit from a creditor or student loan order.
+//!
+//! No order takes more than the pay left after taxes and the other post-tax deductions, so garnishments never make net pay negative. Whatever an order asks for that is not withheld is left in arrears.
+
+use crate::EmploymentScenario;
+use crate::constants::{
+    CCPA_CREDITOR_GARNISHMENT_RATE, CCPA_MINIMUM_WAGE_MULTIPLE, CCPA_SUPPORT_ARREARS_RATE,
+    CCPA_SUPPORT_RATE, CCPA_SUPPORT_SECOND_FAMILY_RATE, FEDERAL_MINIMUM_WAGE,
+    STUDENT_LOAN_GARNISHMENT_RATE,
+};
+use crate::money::Money;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Kind of garnishment order, in the order garnishments are withheld
+/// # Variants
+/// * `ChildSupport` - child support or alimony order
+/// * `FederalTaxLevy` - IRS levy on wages for unpaid federal taxes
+/// * `StudentLoan` - administrative wage garnishment of a defaulted federal student loan
+/// * `Creditor` - ordinary garnishment for any other debt, e.g. a court judgment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum GarnishmentKind {
+    ChildSupport,
+    FederalTaxLevy,
+    StudentLoan,
+    Creditor,
+}
+
+/// Facts about the employee's support order that set the CCPA support limit
+/// # Fields
+/// * `supports_second_family` - the employee supports another spouse or child than the one the order is for (50% limit instead of 60%)
+/// * `arrears_over_12_weeks` - the support is more than 12 weeks in arrears (5% more)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SupportOrder {
+    pub supports_second_family: bool,
+    pub arrears_over_12_weeks: bool,
+}
+
+impl SupportOrder {
+    /// Share of disposable earnings support orders may take (50%, 55%, 60% or 65%)
+    pub fn limit_rate(&self) -> f32 {
+        let rate = if self.supports_second_family {
+            CCPA_SUPPORT_SECOND_FAMILY_RATE
+        } else {
+            CCPA_SUPPORT_RATE
+        };
+        if self.arrears_over_12_weeks {
+            rate + CCPA_SUPPORT_ARREARS_RATE
+        } else {
+            rate
+        }
+    }
+}
+
+/// A garnishment order withheld from a paycheck
+/// # Fields
+/// * `name` - display name of the deduction
+/// * `kind` - the kind of order
+/// * `ordered` - amount the order asks for from the paycheck
+/// * `withheld` - amount withheld, after the limits and the orders ahead of it
+/// * `arrears` - amount ordered but not withheld from the paycheck
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GarnishmentLine {
+    pub name: String,
+    pub kind: GarnishmentKind,
+    pub ordered: Money,
+    pub withheld: Money,
+    pub arrears: Money,
+}
+
+impl fmt::Display for GarnishmentLine {
+    /// Formats as e.g. "Wage Garnishment: $150.00 of the $600.00 ordered is withheld, $450.00 is left in arrears"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: ${} of the ${} ordered is withheld",
+            self.name, self.withheld, self.ordered
+        )?;
+        if !self.arrears.is_zero() {
+            write!(f, ", ${} is left in arrears", self.arrears)?;
+        }
+        Ok(())
+    }
+}
+
+impl EmploymentScenario {
+    /// Caps the garnishment orders among `posttax` (the amount of each post-tax deduction, in the scenario's order) at their limits and returns each order, in the order they are withheld.
+    /// # Arguments
+    /// * `posttax` - amounts of the post-tax deductions, with the garnishments replaced by the amounts withheld
+    /// * `disposable_earnings` - gross pay less federal income tax, Social Security and Medicare
+    /// * `after_tax_pay` - taxable wages less the same taxes, the pay left for post-tax deductions
+    pub(crate) fn withhold_garnishments(
+        &self,
+        posttax: &mut [Money],
+        disposable_earnings: Money,
+        after_tax_pay: Money,
+    ) -> Vec<GarnishmentLine> {
+        let deductions = self.posttax_deductions.get_posttax_deductions();
+        let mut orders: Vec<(usize, GarnishmentKind)> = deductions
+            .iter()
+            .enumerate()
+            .filter_map(|(index, deduction)| Some((index, deduction.garnishment_kind()?)))
+            .collect();
+        orders.sort_by_key(|(_, kind)| *kind);
+
+        let other_deductions: Money = deductions
+            .iter()
+            .zip(posttax.iter())
+            .filter(|(deduction, _)| deduction.garnishment_kind().is_none())
+            .map(|(_, amount)| *amount)
+            .sum();
+        let mut pay_left = (after_tax_pay - other_deductions).max(Money::ZERO);
+
+        let disposable_earnings = disposable_earnings.max(Money::ZERO);
+        let protected_earnings = FEDERAL_MINIMUM_WAGE
+            .multiply(CCPA_MINIMUM_WAGE_MULTIPLE * self.pay_frequency.weeks_per_period());
+        let creditor_limit = disposable_earnings
+            .multiply(CCPA_CREDITOR_GARNISHMENT_RATE)
+            .min(disposable_earnings - protected_earnings)
+            .max(Money::ZERO);
+        let support_limit = disposable_earnings.multiply(self.support_order.limit_rate());
+        let student_loan_limit = disposable_earnings.multiply(STUDENT_LOAN_GARNISHMENT_RATE);
+        let levy_exempt_amount = self
+            .tax_year
+            .filing_status(self.filing_status)
+            .standard_deduction
+            .divide(self.pay_frequency.periods_per_year());
+
+        let mut support = Money::ZERO;
+        let mut student_loans = Money::ZERO;
+        let mut under_ccpa = Money::ZERO;
+        let mut levy_exempt_pay = Money::ZERO;
+        orders
+            .into_iter()
+            .map(|(index, kind)| {
+                let ordered = posttax[index];
+                let limit = match kind {
+                    GarnishmentKind::ChildSupport => support_limit - support,
+                    GarnishmentKind::FederalTaxLevy => pay_left - levy_exempt_amount,
+                    GarnishmentKind::StudentLoan => {
+                        (student_loan_limit - student_loans).min(creditor_limit - under_ccpa)
+                    }
+                    GarnishmentKind::Creditor => creditor_limit - under_ccpa,
+                };
+                let withheld = ordered
+                    .min(limit)
+                    .min(pay_left - levy_exempt_pay)
+                    .max(Money::ZERO);
+                match kind {
+                    GarnishmentKind::ChildSupport => support += withheld,
+                    GarnishmentKind::StudentLoan => student_loans += withheld,
+                    _ => {}
+                }
+                if kind == GarnishmentKind::FederalTaxLevy {
+                    // the pay exempt from the levy stays with the employee
+                    if !ordered.is_zero() {
+                        levy_exempt_pay = levy_exempt_amount;
+                    }
+                } else {
+                    under_ccpa += withheld;
+                }
+                pay_left -= withheld;
+                posttax[index] = withheld;
+                GarnishmentLine {
+                    name: deductions[index].name().to_string(),
+                    kind,
+                    ordered,
+                    withheld,
+                    arrears: ordered - withheld,
+                }
+            })
+            .collect()
+    }
+}
+
+// UNIT TESTS FOR GARNISHMENTS MODULE
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::ScenarioBuilder;
+    use crate::*;
+
+    fn garnishment(
+        amount: i64,
+        kind: fn(Option<DeductionAmount>) -> PostTaxDeduction,
+    ) -> PostTaxDeduction {
+        kind(Some(Money::new(amount, 0).into()))
+    }
+
+    #[test]
+    fn test_support_limit_rates() {
+        let share = |order: SupportOrder| Money::new(100, 0).multiply(order.limit_rate());
+        let mut order = SupportOrder::default();
+        assert_eq!(share(order), Money::new(60, 0));
+        order.arrears_over_12_weeks = true;
+        assert_eq!(share(order), Money::new(65, 0));
+        order.supports_second_family = true;
+        assert_eq!(share(order), Money::new(55, 0));
+        order.arrears_over_12_weeks = false;
+        assert_eq!(share(order), Money::new(50, 0));
+    }
+
+    #[test]
+    fn test_creditor_garnishment_limits() {
+        // $25/hour for 40 hours is $2,000 gross per paycheck, and the creditor gets 25% of $1,690.85 disposable earnings
+        let breakdown = ScenarioBuilder::hourly(25, 40.0)
+            .posttax(vec![garnishment(600, PostTaxDeduction::WageGarnishment)])
+            .build()
+            .calculate_paycheck_breakdown();
+        assert_eq!(breakdown.garnishments[0].withheld, Money::new(422, 71));
+        assert_eq!(breakdown.garnishments[0].arrears, Money::new(177, 29));
+        assert_eq!(breakdown.posttax_deductions[0].amount, Money::new(422, 71));
+
+        // $300 a week leaves $277.05 disposable, only $59.55 over 30 times the minimum wage ($217.50)
+        let mut low_wage = ScenarioBuilder::hourly(10, 30.0)
+            .posttax(vec![garnishment(100, PostTaxDeduction::WageGarnishment)])
+            .build();
+        low_wage.pay_frequency = PayFrequency::Weekly;
+        let breakdown = low_wage.calculate_paycheck_breakdown();
+        assert_eq!(breakdown.garnishments[0].withheld, Money::new(59, 55));
+    }
+
+    #[test]
+    fn test_support_is_withheld_first() {
+        let mut scenario = ScenarioBuilder::hourly(25, 40.0)
+            .posttax(vec![
+                garnishment(300, PostTaxDeduction::WageGarnishment),
+                garnishment(300, PostTaxDeduction::ChildSupport),
+            ])
+            .build();
+        let breakdown = scenario.calculate_paycheck_breakdown();
+        let kinds: Vec<GarnishmentKind> = breakdown
+            .garnishments
+            .iter()
+            .map(|garnishment| garnishment.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![GarnishmentKind::ChildSupport, GarnishmentKind::Creditor]
+        );
+        // the creditor gets what support leaves of the 25% limit
+        assert_eq!(breakdown.garnishments[0].withheld, Money::new(300, 0));
+        assert_eq!(breakdown.garnishments[1].withheld, Money::new(122, 71));
+        assert_eq!(breakdown.posttax_deductions[0].amount, Money::new(122, 71));
+
+        // support over 25% of disposable earnings leaves nothing for the creditor, and stops at 50%
+        scenario.posttax_deductions = PostTaxDeductions::new(vec![
+            garnishment(1000, PostTaxDeduction::ChildSupport),
+            garnishment(300, PostTaxDeduction::WageGarnishment),
+        ]);
+        scenario.support_order.supports_second_family = true;
+        let breakdown = scenario.calculate_paycheck_breakdown();
+        assert_eq!(breakdown.garnishments[0].withheld, Money::new(845, 43));
+        assert_eq!(breakdown.garnishments[0].arrears, Money::new(154, 57));
+        assert_eq!(breakdown.garnishments[1].withheld, Money::ZERO);
+        assert_eq!(breakdown.garnishments[1].arrears, Money::new(300, 0));
+    }
+
+    #[test]
+    fn test_tax_levy_and_student_loan() {
+        let breakdown = ScenarioBuilder::hourly(25, 40.0)
+            .posttax(vec![
+                garnishment(2000, PostTaxDeduction::FederalTaxLevy),
+                garnishment(400, PostTaxDeduction::StudentLoan),
+            ])
+            .build()
+            .calculate_paycheck_breakdown();
+        // the levy leaves the $16,100 standard deduction / 26 = $619.23 exempt, and the student loan cannot take it
+        let levy_exempt_amount = Money::new(619, 23);
+        assert_eq!(breakdown.garnishments[0].withheld, Money::new(1071, 62));
+        assert_eq!(breakdown.garnishments[1].withheld, Money::ZERO);
+        assert_eq!(breakdown.garnishments[1].arrears, Money::new(400, 0));
+        assert!(breakdown.net_pay >= levy_exempt_amount);
+
+        // a smaller levy leaves room for 15% of disposable earnings
+        let breakdown = ScenarioBuilder::hourly(25, 40.0)
+            .posttax(vec![
+                garnishment(500, PostTaxDeduction::FederalTaxLevy),
+                garnishment(400, PostTaxDeduction::StudentLoan),
+            ])
+            .build()
+            .calculate_paycheck_breakdown();
+        assert_eq!(breakdown.garnishments[0].withheld, Money::new(500, 0));
+        assert_eq!(breakdown.garnishments[1].withheld, Money::new(253, 63));
+        assert!(breakdown.net_pay >= levy_exempt_amount);
+    }
+
+    #[test]
+    fn test_garnishments_never_make_net_pay_negative() {
+        let breakdown = ScenarioBuilder::hourly(25, 40.0)
+            .posttax(vec![
+                PostTaxDeduction::VoluntaryLife(Some(Money::new(1500, 0).into())),
+                garnishment(1000, PostTaxDeduction::ChildSupport),
+            ])
+            .build()
+            .calculate_paycheck_breakdown();
+        assert_eq!(breakdown.garnishments[0].withheld, Money::new(190, 85));
+        assert_eq!(breakdown.net_pay, Money::ZERO);
+    }
+}
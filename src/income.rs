@@ -1,28 +1,51 @@
-//! Module for handling paycheck income calculations for hourly paid employees
-//! This module calculates from a bi-weekly paycheck perspective to synthesize how an employee thinks about, views, and plans their income.
+//! Module for handling paycheck income calculations for hourly and salaried employees
+//! This module calculates from a single paycheck perspective to synthesize how an employee thinks about, views, and plans their income. Paychecks default to bi-weekly, and the `PayFrequency` sets how many weeks each paycheck covers.
+//! Overtime applies to hourly employees and to salaried employees who are non-exempt under the FLSA; exempt salaried employees are paid their salary regardless of hours.
 
 use crate::constants::*;
-use crate::utils::round_2_decimals;
+use crate::money::Money;
+use serde::{Deserialize, Serialize};
 
-/// Determine gross bi-weekly paycheck based on hourly rate and hours worked per week
+/// Gross pay for a single paycheck, split into regular and overtime pay
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GrossPay {
+    pub regular: Money,
+    pub overtime: Money,
+}
+
+impl GrossPay {
+    /// Total gross pay (regular + overtime)
+    pub fn total(&self) -> Money {
+        self.regular + self.overtime
+    }
+}
+
+/// Determine gross pay for one paycheck, split into regular and overtime pay, based on hourly rate and hours worked per week
 /// # Arguments
 /// * `rate` - hourly pay rate
 /// * `hours_per_week` - number of hours worked per week
+/// * `pay_frequency` - how often paychecks are issued
 /// # Returns
-/// * `f32` - gross bi-weekly paycheck amount rounded to 2 decimal places
+/// * `GrossPay` - regular and overtime pay for the paycheck, each rounded to the cent
 /// # Example
 /// ```
-/// use paycheck_utils::income::determine_gross_paycheck;
-/// use paycheck_utils::round_2_decimals;
+/// use paycheck_utils::income::determine_gross_pay;
+/// use paycheck_utils::{Money, PayFrequency};
 ///
-/// let gross_paycheck = determine_gross_paycheck(20.0, 45.0);
-/// assert_eq!(gross_paycheck, 1900.00);
+/// let gross_pay = determine_gross_pay(Money::new(20, 0), 45.0, PayFrequency::BiWeekly);
+/// assert_eq!(gross_pay.regular, Money::new(1600, 0));
+/// assert_eq!(gross_pay.overtime, Money::new(300, 0));
+/// assert_eq!(gross_pay.total(), Money::new(1900, 0));
 /// ```
 /// # Notes
 /// * Overtime is calculated at time and a half for hours worked over 40 hours per week
 /// * Standard hours are capped at 40 hours per week for regular pay calculation
-/// * Bi-weekly paycheck is calculated over 2 week pay periods
-pub fn determine_gross_paycheck(rate: f32, hours_per_week: f32) -> f32 {
+/// * Overtime is determined per week, then the weekly pay is multiplied by the weeks in the pay period (2 for bi-weekly, 52/24 for semi-monthly, 52/12 for monthly)
+pub fn determine_gross_pay(
+    rate: Money,
+    hours_per_week: f32,
+    pay_frequency: PayFrequency,
+) -> GrossPay {
     let regular_hours = if hours_per_week > STANDARD_HOURS_PER_WEEK {
         STANDARD_HOURS_PER_WEEK
     } else {
@@ -35,10 +58,165 @@ pub fn determine_gross_paycheck(rate: f32, hours_per_week: f32) -> f32 {
         0.0
     };
 
-    let gross_paycheck =
-        ((regular_hours * rate) + (overtime_hours * (rate * OVERTIME_MULTIPLIER))) * PAY_PERIOD;
+    let weeks_per_period = pay_frequency.weeks_per_period();
+
+    GrossPay {
+        regular: rate.multiply(regular_hours * weeks_per_period),
+        overtime: rate.multiply(overtime_hours * OVERTIME_MULTIPLIER * weeks_per_period),
+    }
+}
+
+/// How an employee is paid
+/// # Variants
+/// * `Hourly` - hourly `rate` for the `hours_per_week` worked
+/// * `Salary` - `annual_salary` paid evenly each pay period, with `hours_per_week` worked and FLSA `exempt` status (exempt employees are not paid overtime)
+/// # Example
+/// ```
+/// use paycheck_utils::{Money, PayBasis, PayFrequency};
+///
+/// let salary = PayBasis::Salary {
+///     annual_salary: Money::new(65_000, 0),
+///     hours_per_week: 45.0,
+///     exempt: true,
+/// };
+/// assert_eq!(salary.gross_pay(PayFrequency::BiWeekly).total(), Money::new(2500, 0));
+/// assert_eq!(salary.gross_pay(PayFrequency::Monthly).total(), Money::new(5416, 67));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum PayBasis {
+    Hourly {
+        rate: Money,
+        hours_per_week: f32,
+    },
+    Salary {
+        annual_salary: Money,
+        hours_per_week: f32,
+        exempt: bool,
+    },
+}
+
+impl Default for PayBasis {
+    fn default() -> Self {
+        PayBasis::Hourly {
+            rate: Money::ZERO,
+            hours_per_week: 0.0,
+        }
+    }
+}
+
+impl PayBasis {
+    /// Hours worked per week
+    pub fn hours_per_week(&self) -> f32 {
+        match self {
+            PayBasis::Hourly { hours_per_week, .. } | PayBasis::Salary { hours_per_week, .. } => {
+                *hours_per_week
+            }
+        }
+    }
+
+    /// The same pay basis with a different number of hours worked per week
+    pub fn with_hours_per_week(self, hours: f32) -> Self {
+        match self {
+            PayBasis::Hourly { rate, .. } => PayBasis::Hourly {
+                rate,
+                hours_per_week: hours,
+            },
+            PayBasis::Salary {
+                annual_salary,
+                exempt,
+                ..
+            } => PayBasis::Salary {
+                annual_salary,
+                hours_per_week: hours,
+                exempt,
+            },
+        }
+    }
+
+    /// Gross pay for a single paycheck, split into regular and overtime pay
+    pub fn gross_pay(&self, pay_frequency: PayFrequency) -> GrossPay {
+        match *self {
+            PayBasis::Hourly {
+                rate,
+                hours_per_week,
+            } => determine_gross_pay(rate, hours_per_week, pay_frequency),
+            PayBasis::Salary {
+                annual_salary,
+                hours_per_week,
+                exempt,
+            } => determine_salaried_gross_pay(annual_salary, hours_per_week, exempt, pay_frequency),
+        }
+    }
+}
+
+/// Determine gross pay for one paycheck, split into regular and overtime pay, for a salaried employee
+/// # Arguments
+/// * `annual_salary` - annual salary
+/// * `hours_per_week` - number of hours worked per week
+/// * `exempt` - whether the employee is exempt from overtime under the FLSA
+/// * `pay_frequency` - how often paychecks are issued
+/// # Returns
+/// * `GrossPay` - regular and overtime pay for the paycheck, each rounded to the cent
+/// # Example
+/// ```
+/// use paycheck_utils::income::determine_salaried_gross_pay;
+/// use paycheck_utils::{Money, PayFrequency};
+///
+/// let gross_pay = determine_salaried_gross_pay(Money::new(52_000, 0), 45.0, false, PayFrequency::BiWeekly);
+/// assert_eq!(gross_pay.regular, Money::new(2000, 0));
+/// assert_eq!(gross_pay.overtime, Money::new(375, 0));
+///
+/// let gross_pay = determine_salaried_gross_pay(Money::new(52_000, 0), 45.0, true, PayFrequency::BiWeekly);
+/// assert_eq!(gross_pay.overtime, Money::ZERO);
+/// ```
+/// # Notes
+/// * Regular pay is the annual salary divided evenly over the pay periods in a year
+/// * Non-exempt overtime is paid at time and a half of the regular rate (weekly salary / 40 hours, rounded to the cent) for hours worked over 40 hours per week, for each week in the pay period
+/// * Exempt employees are never paid overtime
+pub fn determine_salaried_gross_pay(
+    annual_salary: Money,
+    hours_per_week: f32,
+    exempt: bool,
+    pay_frequency: PayFrequency,
+) -> GrossPay {
+    let overtime_hours = if !exempt && hours_per_week > STANDARD_HOURS_PER_WEEK {
+        hours_per_week - STANDARD_HOURS_PER_WEEK
+    } else {
+        0.0
+    };
+    let regular_rate = annual_salary.divide(WEEKS_PER_YEAR * STANDARD_HOURS_PER_WEEK);
+
+    GrossPay {
+        regular: annual_salary.divide(pay_frequency.periods_per_year()),
+        overtime: regular_rate
+            .multiply(overtime_hours * OVERTIME_MULTIPLIER * pay_frequency.weeks_per_period()),
+    }
+}
 
-    round_2_decimals(gross_paycheck)
+/// Determine gross paycheck based on hourly rate and hours worked per week
+/// # Arguments
+/// * `rate` - hourly pay rate
+/// * `hours_per_week` - number of hours worked per week
+/// * `pay_frequency` - how often paychecks are issued
+/// # Returns
+/// * `Money` - gross paycheck amount rounded to the cent
+/// # Example
+/// ```
+/// use paycheck_utils::income::determine_gross_paycheck;
+/// use paycheck_utils::{Money, PayFrequency};
+///
+/// let gross_paycheck = determine_gross_paycheck(Money::new(20, 0), 45.0, PayFrequency::BiWeekly);
+/// assert_eq!(gross_paycheck, Money::new(1900, 0));
+/// ```
+/// # Notes
+/// * See `determine_gross_pay` for the regular and overtime split
+pub fn determine_gross_paycheck(
+    rate: Money,
+    hours_per_week: f32,
+    pay_frequency: PayFrequency,
+) -> Money {
+    determine_gross_pay(rate, hours_per_week, pay_frequency).total()
 }
 
 // UNIT TESTS FOR INCOME MODULE
@@ -48,17 +226,76 @@ mod tests {
     use super::*;
     #[test]
     fn test_determine_gross_paycheck() {
-        let rate = 20.0;
+        let rate = Money::new(20, 0);
         let hours_per_week = 45.0;
-        let gross_paycheck = determine_gross_paycheck(rate, hours_per_week);
-        assert_eq!(gross_paycheck, 1900.00);
+        let gross_paycheck = determine_gross_paycheck(rate, hours_per_week, PayFrequency::BiWeekly);
+        assert_eq!(gross_paycheck, Money::new(1900, 0));
     }
 
     #[test]
     fn test_determine_gross_paycheck_no_overtime() {
-        let rate = 15.0;
+        let rate = Money::new(15, 0);
         let hours_per_week = 35.0;
-        let gross_paycheck = determine_gross_paycheck(rate, hours_per_week);
-        assert_eq!(gross_paycheck, 1050.00);
+        let gross_paycheck = determine_gross_paycheck(rate, hours_per_week, PayFrequency::BiWeekly);
+        assert_eq!(gross_paycheck, Money::new(1050, 0));
+    }
+
+    #[test]
+    fn test_determine_gross_pay_split() {
+        let gross_pay = determine_gross_pay(Money::new(25, 0), 45.0, PayFrequency::BiWeekly);
+        assert_eq!(gross_pay.regular, Money::new(2000, 0));
+        assert_eq!(gross_pay.overtime, Money::new(375, 0));
+    }
+
+    #[test]
+    fn test_determine_salaried_gross_pay() {
+        let non_exempt = determine_salaried_gross_pay(
+            Money::new(52_000, 0),
+            45.0,
+            false,
+            PayFrequency::BiWeekly,
+        );
+        assert_eq!(non_exempt.regular, Money::new(2000, 0));
+        assert_eq!(non_exempt.overtime, Money::new(375, 0));
+
+        let exempt =
+            determine_salaried_gross_pay(Money::new(52_000, 0), 45.0, true, PayFrequency::BiWeekly);
+        assert_eq!(exempt.regular, Money::new(2000, 0));
+        assert_eq!(exempt.overtime, Money::ZERO);
+
+        let under_forty = determine_salaried_gross_pay(
+            Money::new(52_000, 0),
+            30.0,
+            false,
+            PayFrequency::BiWeekly,
+        );
+        assert_eq!(under_forty.total(), Money::new(2000, 0));
+    }
+
+    #[test]
+    fn test_pay_basis_gross_pay() {
+        let hourly = PayBasis::Hourly {
+            rate: Money::new(25, 0),
+            hours_per_week: 45.0,
+        };
+        assert_eq!(
+            hourly.gross_pay(PayFrequency::BiWeekly),
+            determine_gross_pay(Money::new(25, 0), 45.0, PayFrequency::BiWeekly)
+        );
+        assert_eq!(hourly.hours_per_week(), 45.0);
+    }
+
+    #[test]
+    fn test_determine_gross_pay_by_pay_frequency() {
+        let rate = Money::new(25, 0);
+        let weekly = determine_gross_pay(rate, 45.0, PayFrequency::Weekly);
+        assert_eq!(weekly.regular, Money::new(1000, 0));
+        assert_eq!(weekly.overtime, Money::new(187, 50));
+        // 52 weeks / 24 semi-monthly paychecks
+        let semi_monthly = determine_gross_pay(rate, 40.0, PayFrequency::SemiMonthly);
+        assert_eq!(semi_monthly.total(), Money::new(2166, 67));
+        let monthly =
+            determine_salaried_gross_pay(Money::new(60_000, 0), 40.0, true, PayFrequency::Monthly);
+        assert_eq!(monthly.total(), Money::new(5000, 0));
     }
 }
@@ -1,20 +1,22 @@
 //! This module handles all user interaction to gather the necessary information to create an employment scenario struct
 //! This includes functions for displaying prompts, receiving input, and showing results. The main function in this module is `get_user_input` which orchestrates the entire process of gathering information from the user and creating an employment scenario struct.
 //!
-//! The `get_user_input` function first prompts the user to create an employment scenario by calling the `create_scenario` function, which gathers information about the user's hourly rate and hours worked per week. Then it prompts the user to enter their living expenses by calling the `get_expenses` function, which gathers information about various expense categories. Finally, it prompts the user to enter their deductions by calling the `get_deductions` function, which gathers information about both pre-tax and post-tax deductions. After gathering all the necessary information, it confirms the inputs with the user and then converts the inputs into an employment scenario struct using the `convert_inputs_to_struct` function.
+//! The `get_user_input` function first prompts the user to create an employment scenario by calling the `create_scenario` function, which gathers information about the user's pay type, pay frequency, hourly rate or annual salary, hours worked per week, overtime exemption, filing status and Form W-4 adjustments. Then it prompts the user to enter their living expenses by calling the `get_expenses` function, which gathers information about various expense categories and any custom expenses named by the user. Finally, it prompts the user to enter their deductions by calling the `get_deductions` function, which gathers information about both pre-tax and post-tax deductions. After gathering all the necessary information, it confirms the inputs with the user and then converts the inputs into an employment scenario struct using the `convert_inputs_to_struct` function.
 //!
 //! The `create_scenario`, `get_expenses`, and `get_deductions` functions all follow a similar pattern of prompting the user for input, validating the input, and storing it in a HashMap. The `confirm_inputs` function is used to display the gathered information back to the user for confirmation before proceeding to create the employment scenario struct. The `convert_inputs_to_struct` function takes the gathered information from the HashMaps and constructs an `EmploymentScenario` struct with the appropriate fields populated based on the user's input.
 
 /// checks the converted value of the user input to ensure it can be parsed into the expected type (in this case, a float). If the conversion is successful, it returns true; otherwise, it returns false. This function is used in the input validation loops in the `create_scenario`, `get_expenses`, and `get_deductions` functions to ensure that the user enters valid numeric input for the various fields.
 use crate::utils::check_converted_value;
 use crate::{
-    EmploymentScenario, Expense, Expenses, PostTaxDeduction, PostTaxDeductions, PreTaxDeduction,
-    PreTaxDeductions,
+    DeductionAmount, EmploymentScenario, Expense, ExpenseFrequency, ExpenseItem, Expenses,
+    FilingStatus, Money, PayBasis, PayFrequency, PostTaxDeduction, PostTaxDeductions,
+    PreTaxDeduction, PreTaxDeductions, TaxTreatment, W4,
 };
 use std::any::TypeId;
 use std::collections::HashMap;
 use std::io;
 use std::io::prelude::*;
+use std::path::PathBuf;
 
 /// main function to orchestrate user input and create employment scenario struct
 /// This function will call the other functions in this module to gather information from the user and create an employment scenario struct based on that information. It will start by getting the payrate and hours worked per week.
@@ -46,11 +48,24 @@ pub fn get_user_input() -> EmploymentScenario {
     convert_inputs_to_struct(scenario, expenses, deductions)
 }
 
-/// create scenario input by prompting the user for their hourly rate and hours worked per week. The input is cleaned and validated to ensure it can be converted to a float before storing it in a HashMap. The keys of the HashMap are "Rate" and "Hours" and the values are the user input for those fields.
+/// create scenario input by prompting the user for their pay type (hourly or salary) and pay frequency, then their hourly rate or annual salary and hours worked per week, for salaried employees whether they are exempt from overtime, and the Form W-4 filing status and Steps 2 through 4. The pay type, exempt, filing status and W-4 Step 2(c) answers are validated against the accepted answers, and the numeric inputs are cleaned and validated to ensure they can be converted to a float before storing them in a HashMap. The keys of the HashMap are "Pay Type", "Pay Frequency", "Rate" or "Salary", "Hours", "Exempt", "Filing Status", "W-4 Multiple Jobs", "W-4 Dependents Credit", "W-4 Other Income", "W-4 Deductions" and "W-4 Extra Withholding" and the values are the user input for those fields.
 fn create_scenario() -> HashMap<String, String> {
     let mut inputs: HashMap<String, String> = HashMap::new();
     let mut input = String::new();
-    let employed = ["Rate", "Hours"];
+
+    let pay_type = prompt_choice("Pay Type (hourly/salary)", &["hourly", "salary"], "hourly");
+    let employed = if pay_type == "salary" {
+        ["Salary", "Hours"]
+    } else {
+        ["Rate", "Hours"]
+    };
+    inputs.insert("Pay Type".to_string(), pay_type);
+    let pay_frequency = prompt_choice(
+        "Pay Frequency (weekly/biweekly/semimonthly/monthly)",
+        &["weekly", "biweekly", "semimonthly", "monthly"],
+        "biweekly",
+    );
+    inputs.insert("Pay Frequency".to_string(), pay_frequency);
 
     for value in employed {
         print!("{value}: ");
@@ -74,10 +89,123 @@ fn create_scenario() -> HashMap<String, String> {
         input.clear();
     }
 
+    if inputs["Pay Type"] == "salary" {
+        let exempt = prompt_choice("Exempt from overtime (y/n)", &["y", "n"], "n");
+        inputs.insert("Exempt".to_string(), exempt);
+    }
+
+    // Form W-4 filing status and Steps 2 through 4, which adjust the federal income tax withholding
+    println!("\nNext, the Form W-4 adjustments. Enter 0 for any step left blank on your W-4.\n");
+    let filing_status = prompt_choice(
+        "W-4 Step 1(c): Filing Status (single/married-jointly/married-separately/head-of-household)",
+        &[
+            "single",
+            "married-jointly",
+            "married-separately",
+            "head-of-household",
+        ],
+        "single",
+    );
+    inputs.insert("Filing Status".to_string(), filing_status);
+    let multiple_jobs = prompt_choice(
+        "W-4 Step 2(c): Multiple Jobs or Spouse Works (y/n)",
+        &["y", "n"],
+        "n",
+    );
+    inputs.insert("W-4 Multiple Jobs".to_string(), multiple_jobs);
+    let w4_steps = [
+        (
+            "W-4 Dependents Credit",
+            "W-4 Step 3: Annual Dependents Credit",
+        ),
+        ("W-4 Other Income", "W-4 Step 4(a): Annual Other Income"),
+        ("W-4 Deductions", "W-4 Step 4(b): Annual Deductions"),
+        (
+            "W-4 Extra Withholding",
+            "W-4 Step 4(c): Extra Withholding per Paycheck",
+        ),
+    ];
+    for (key, label) in w4_steps {
+        print!("{label}: ");
+        io::stdout().flush().unwrap_or_default();
+        io::stdin().read_line(&mut input).unwrap_or_default();
+        loop {
+            if check_converted_value(&input.trim().parse::<f32>(), TypeId::of::<f32>()) {
+                break;
+            } else {
+                print!(
+                    "Please enter a valid number for {label} (examples: 0, 2000, or 25.00) --> {label}: "
+                );
+                input.clear();
+                io::stdout().flush().unwrap_or_default();
+                io::stdin().read_line(&mut input).unwrap_or_default();
+            }
+        }
+        inputs.insert(key.to_string(), input.trim().to_string());
+        input.clear();
+    }
+
     inputs
 }
 
+/// ask the user whether to save the confirmed inputs to a scenario file, and if so prompt for a file path until it ends in `.toml` or `.json`. Returns `None` when the user does not want to save. The saved file can be used later with `check-paycheck run <FILE>`.
+pub fn get_save_path() -> Option<PathBuf> {
+    if prompt_choice(
+        "Save these inputs to a scenario file? (y/n)",
+        &["y", "n"],
+        "n",
+    ) == "n"
+    {
+        return None;
+    }
+
+    let mut input = String::new();
+    print!("File (.toml or .json): ");
+    io::stdout().flush().unwrap_or_default();
+    io::stdin().read_line(&mut input).unwrap_or_default();
+    loop {
+        let path = PathBuf::from(input.trim());
+        if matches!(
+            path.extension().and_then(|ext| ext.to_str()),
+            Some("toml" | "json")
+        ) {
+            return Some(path);
+        }
+        print!(
+            "Please enter a file name ending in .toml or .json (example: scenario.toml) --> File: "
+        );
+        input.clear();
+        io::stdout().flush().unwrap_or_default();
+        if io::stdin().read_line(&mut input).unwrap_or_default() == 0 {
+            return None;
+        }
+    }
+}
+
+/// prompt the user with `label` until they enter one of the `choices` (case insensitive) and return the lowercase choice. The `default` choice is returned when there is no more input (e.g. piped input has ended).
+fn prompt_choice(label: &str, choices: &[&str], default: &str) -> String {
+    let mut input = String::new();
+
+    print!("{label}: ");
+    io::stdout().flush().unwrap_or_default();
+    let mut bytes_read = io::stdin().read_line(&mut input).unwrap_or_default();
+    loop {
+        let answer = input.trim().to_lowercase();
+        if choices.contains(&answer.as_str()) {
+            return answer;
+        }
+        if bytes_read == 0 {
+            return default.to_string();
+        }
+        print!("Please enter one of {} --> {label}: ", choices.join(", "));
+        input.clear();
+        io::stdout().flush().unwrap_or_default();
+        bytes_read = io::stdin().read_line(&mut input).unwrap_or_default();
+    }
+}
+
 /// prompt user for expenses input and return a HashMap of the inputs. Cleans the input and validates that it can be converted to a float before storing it in the HashMap. The keys of the HashMap are the expense categories and the values are the amounts entered by the user.
+/// After the standard categories the user can add any number of named custom expenses, stored under the keys "Custom Expense N" (the name), "Custom Expense N Amount", "Custom Expense N Frequency" (how often it is paid) and "Custom Expense N Group" (empty when no group was entered).
 fn get_expenses() -> HashMap<String, String> {
     let mut inputs: HashMap<String, String> = HashMap::new();
     let mut input = String::new();
@@ -101,7 +229,7 @@ fn get_expenses() -> HashMap<String, String> {
         io::stdout().flush().unwrap_or_default();
         io::stdin().read_line(&mut input).unwrap_or_default();
         loop {
-            if check_converted_value(&input.trim().parse::<f32>(), TypeId::of::<f32>()) {
+            if check_converted_value(&input.trim().parse::<Money>(), TypeId::of::<Money>()) {
                 break;
             } else {
                 print!(
@@ -118,10 +246,69 @@ fn get_expenses() -> HashMap<String, String> {
         input.clear();
     }
 
+    println!(
+        "\nAdd any other expenses (childcare, student loans, subscriptions, pets, medical bills...) by name, or leave the name blank to finish.\n"
+    );
+
+    for number in 1.. {
+        let key = format!("Custom Expense {number}");
+        print!("Expense Name: ");
+        io::stdout().flush().unwrap_or_default();
+        if io::stdin().read_line(&mut input).unwrap_or_default() == 0 || input.trim().is_empty() {
+            break;
+        }
+        let name = input.trim().to_string();
+        input.clear();
+
+        print!("{name}: ");
+        io::stdout().flush().unwrap_or_default();
+        io::stdin().read_line(&mut input).unwrap_or_default();
+        loop {
+            if check_converted_value(&input.trim().parse::<Money>(), TypeId::of::<Money>()) {
+                break;
+            } else {
+                print!(
+                    "Please enter a valid number for {name} (examples: 25, 25.5, or 25.00) --> {name}: "
+                );
+                input.clear();
+                io::stdout().flush().unwrap_or_default();
+                io::stdin().read_line(&mut input).unwrap_or_default();
+            }
+        }
+        inputs.insert(format!("{key} Amount"), input.trim().to_string());
+        input.clear();
+
+        let frequency = prompt_choice(
+            &format!(
+                "{name} Frequency (weekly/biweekly/monthly/quarterly/semiannual/annual/onetime)"
+            ),
+            &[
+                "weekly",
+                "biweekly",
+                "monthly",
+                "quarterly",
+                "semiannual",
+                "annual",
+                "onetime",
+            ],
+            "monthly",
+        );
+        inputs.insert(format!("{key} Frequency"), frequency);
+
+        print!("{name} Group (optional, e.g. Family): ");
+        io::stdout().flush().unwrap_or_default();
+        io::stdin().read_line(&mut input).unwrap_or_default();
+        inputs.insert(format!("{key} Group"), input.trim().to_string());
+        input.clear();
+
+        inputs.insert(key, name);
+    }
+
     inputs
 }
 
-/// prompt user for deductions input and return a HashMap of the inputs. Cleans the input and validates that it can be converted to a float before storing it in the HashMap. The keys of the HashMap are the deduction categories and the values are the amounts entered by the user. This function handles both pre-tax and post-tax deductions, prompting the user separately for each type of deduction.
+/// prompt user for deductions input and return a HashMap of the inputs. Cleans the input and validates that it can be converted to a `DeductionAmount` (a dollar amount or a percentage of pay) before storing it in the HashMap. The keys of the HashMap are the deduction categories and the values are the amounts entered by the user. This function handles both pre-tax and post-tax deductions, prompting the user separately for each type of deduction.
+/// After each set of standard categories the user can add named custom deductions, stored under the keys "Custom Pre-Tax N" / "Custom Post-Tax N" (the name) and "... Amount", plus "Custom Pre-Tax N Treatment" (federal-only or federal-and-fica).
 fn get_deductions() -> HashMap<String, String> {
     let mut inputs: HashMap<String, String> = HashMap::new();
     let mut input = String::new();
@@ -139,11 +326,14 @@ fn get_deductions() -> HashMap<String, String> {
         "Voluntary ADD",
         "Voluntary STD",
         "Voluntary LTD",
+        "Child Support",
+        "Federal Tax Levy",
+        "Student Loan Garnishment",
         "Wage Garnishment",
     ];
 
     println!(
-        "\nDeductions come in two flavors: pre-tax and post-tax.\nLet's start with the pre-tax deductions.\n"
+        "\nDeductions come in two flavors: pre-tax and post-tax.\nEnter each one as an amount per paycheck, or as a percentage of gross pay (6%) or of regular pay without overtime (6%regular).\nLet's start with the pre-tax deductions.\n"
     );
 
     for pre in pretax_categories {
@@ -151,11 +341,14 @@ fn get_deductions() -> HashMap<String, String> {
         io::stdout().flush().unwrap_or_default();
         io::stdin().read_line(&mut input).unwrap_or_default();
         loop {
-            if check_converted_value(&input.trim().parse::<f32>(), TypeId::of::<f32>()) {
+            if check_converted_value(
+                &input.trim().parse::<DeductionAmount>(),
+                TypeId::of::<DeductionAmount>(),
+            ) {
                 break;
             } else {
                 print!(
-                    "Please enter a valid number for {pre} (examples: 25, 25.5, or 25.00) --> {pre}: "
+                    "Please enter a valid number for {pre} (examples: 25, 25.00, or 6% of gross pay) --> {pre}: "
                 );
                 input.clear();
                 io::stdout().flush().unwrap_or_default();
@@ -168,6 +361,25 @@ fn get_deductions() -> HashMap<String, String> {
         input.clear();
     }
 
+    println!(
+        "\nAdd any other pre-tax deductions (457(b), commuter benefits, dependent care...) by name, or leave the name blank to finish.\n"
+    );
+
+    for number in 1.. {
+        let key = format!("Custom Pre-Tax {number}");
+        let Some((name, amount)) = get_custom_deduction(&mut input) else {
+            break;
+        };
+        let treatment = prompt_choice(
+            &format!("{name} Tax Treatment (federal-only/federal-and-fica)"),
+            &["federal-only", "federal-and-fica"],
+            "federal-and-fica",
+        );
+        inputs.insert(format!("{key} Amount"), amount);
+        inputs.insert(format!("{key} Treatment"), treatment);
+        inputs.insert(key, name);
+    }
+
     println!("\nOk, now the post-tax deductions.\n");
 
     for post in posttax_categories {
@@ -175,11 +387,14 @@ fn get_deductions() -> HashMap<String, String> {
         io::stdout().flush().unwrap_or_default();
         io::stdin().read_line(&mut input).unwrap_or_default();
         loop {
-            if check_converted_value(&input.trim().parse::<f32>(), TypeId::of::<f32>()) {
+            if check_converted_value(
+                &input.trim().parse::<DeductionAmount>(),
+                TypeId::of::<DeductionAmount>(),
+            ) {
                 break;
             } else {
                 print!(
-                    "Please enter a valid number for {post} (examples: 25, 25.5, or 25.00) --> {post}: "
+                    "Please enter a valid number for {post} (examples: 25, 25.00, or 6% of gross pay) --> {post}: "
                 );
                 input.clear();
                 io::stdout().flush().unwrap_or_default();
@@ -192,46 +407,197 @@ fn get_deductions() -> HashMap<String, String> {
         input.clear();
     }
 
+    println!(
+        "\nAdd any other post-tax deductions (union dues, charitable giving...) by name, or leave the name blank to finish.\n"
+    );
+
+    for number in 1.. {
+        let key = format!("Custom Post-Tax {number}");
+        let Some((name, amount)) = get_custom_deduction(&mut input) else {
+            break;
+        };
+        inputs.insert(format!("{key} Amount"), amount);
+        inputs.insert(key, name);
+    }
+
     inputs
 }
 
-/// This function takes the three HashMaps containing the user input for the employment scenario, expenses, and deductions, and converts them into an `EmploymentScenario` struct. It parses the string values from the HashMaps into the appropriate types (e.g., f32) and constructs the `EmploymentScenario` struct with the corresponding fields populated based on the user's input.
+/// prompt user for the name and per-paycheck amount of a custom deduction, returning None when the name is left blank
+fn get_custom_deduction(input: &mut String) -> Option<(String, String)> {
+    print!("Deduction Name: ");
+    io::stdout().flush().unwrap_or_default();
+    if io::stdin().read_line(input).unwrap_or_default() == 0 || input.trim().is_empty() {
+        input.clear();
+        return None;
+    }
+    let name = input.trim().to_string();
+    input.clear();
+
+    print!("{name}: ");
+    io::stdout().flush().unwrap_or_default();
+    io::stdin().read_line(input).unwrap_or_default();
+    loop {
+        if check_converted_value(
+            &input.trim().parse::<DeductionAmount>(),
+            TypeId::of::<DeductionAmount>(),
+        ) {
+            break;
+        } else {
+            print!(
+                "Please enter a valid number for {name} (examples: 25, 25.00, or 6% of gross pay) --> {name}: "
+            );
+            input.clear();
+            io::stdout().flush().unwrap_or_default();
+            io::stdin().read_line(input).unwrap_or_default();
+        }
+    }
+    let amount = input.trim().to_string();
+    input.clear();
+
+    Some((name, amount))
+}
+
+/// This function takes the three HashMaps containing the user input for the employment scenario, expenses, and deductions, and converts them into an `EmploymentScenario` struct. It parses the string values from the HashMaps into the appropriate types (e.g., `Money` for dollar amounts and f32 for hours) and constructs the `EmploymentScenario` struct with the corresponding fields populated based on the user's input.
 pub fn convert_inputs_to_struct(
     sc: HashMap<String, String>,
     ex: HashMap<String, String>,
     de: HashMap<String, String>,
 ) -> EmploymentScenario {
-    let mut scene = EmploymentScenario::default();
-    scene.hourly_rate = sc["Rate"].parse::<f32>().unwrap_or_default();
-    scene.hours_per_week = sc["Hours"].parse::<f32>().unwrap_or_default();
-    scene.expenses = Expenses::new(vec![
-        Expense::Housing(ex["Housing"].parse::<f32>().ok()),
-        Expense::Energy(ex["Energy"].parse::<f32>().ok()),
-        Expense::Water(ex["Water"].parse::<f32>().ok()),
-        Expense::Gas(ex["Gas"].parse::<f32>().ok()),
-        Expense::Internet(ex["Internet"].parse::<f32>().ok()),
-        Expense::Phone(ex["Phone"].parse::<f32>().ok()),
-        Expense::Vehicle(ex["Car Payment"].parse::<f32>().ok()),
-        Expense::VehicleInsurance(ex["Car Insurance"].parse::<f32>().ok()),
-        Expense::VehicleGas(ex["Car Gas"].parse::<f32>().ok()),
-        Expense::Groceries(ex["Groceries"].parse::<f32>().ok()),
-    ]);
-    scene.pretax_deductions = PreTaxDeductions::new(vec![
-        PreTaxDeduction::Medical(de["Medical"].parse::<f32>().ok()),
-        PreTaxDeduction::Dental(de["Dental"].parse::<f32>().ok()),
-        PreTaxDeduction::Vision(de["Vision"].parse::<f32>().ok()),
-        PreTaxDeduction::Traditional401K(de["Traditional401K"].parse::<f32>().ok()),
-        PreTaxDeduction::HSA(de["HSA"].parse::<f32>().ok()),
-        PreTaxDeduction::FSA(de["FSA"].parse::<f32>().ok()),
-    ]);
-    scene.posttax_deductions = PostTaxDeductions::new(vec![
-        PostTaxDeduction::Roth401K(de["Roth401K"].parse::<f32>().ok()),
-        PostTaxDeduction::VoluntaryLife(de["Voluntary Life"].parse::<f32>().ok()),
-        PostTaxDeduction::VoluntaryADD(de["Voluntary ADD"].parse::<f32>().ok()),
-        PostTaxDeduction::VoluntarySTD(de["Voluntary STD"].parse::<f32>().ok()),
-        PostTaxDeduction::VoluntaryLTD(de["Voluntary LTD"].parse::<f32>().ok()),
-        PostTaxDeduction::WageGarnishment(de["Wage Garnishment"].parse::<f32>().ok()),
-    ]);
-
-    scene
+    let hours_per_week = sc["Hours"].parse::<f32>().unwrap_or_default();
+    let pay_basis = match sc.get("Salary") {
+        Some(salary) => PayBasis::Salary {
+            annual_salary: salary.parse::<Money>().unwrap_or_default(),
+            hours_per_week,
+            exempt: sc.get("Exempt").is_some_and(|exempt| exempt == "y"),
+        },
+        None => PayBasis::Hourly {
+            rate: sc["Rate"].parse::<Money>().unwrap_or_default(),
+            hours_per_week,
+        },
+    };
+
+    // the standard categories are monthly, followed by the custom expenses with their own frequency
+    let mut expense_items: Vec<ExpenseItem> = vec![
+        Expense::Housing(ex["Housing"].parse::<Money>().ok()),
+        Expense::Energy(ex["Energy"].parse::<Money>().ok()),
+        Expense::Water(ex["Water"].parse::<Money>().ok()),
+        Expense::Gas(ex["Gas"].parse::<Money>().ok()),
+        Expense::Internet(ex["Internet"].parse::<Money>().ok()),
+        Expense::Phone(ex["Phone"].parse::<Money>().ok()),
+        Expense::Vehicle(ex["Car Payment"].parse::<Money>().ok()),
+        Expense::VehicleInsurance(ex["Car Insurance"].parse::<Money>().ok()),
+        Expense::VehicleGas(ex["Car Gas"].parse::<Money>().ok()),
+        Expense::Groceries(ex["Groceries"].parse::<Money>().ok()),
+    ]
+    .into_iter()
+    .map(ExpenseItem::from)
+    .collect();
+    for number in 1.. {
+        let key = format!("Custom Expense {number}");
+        let Some(name) = ex.get(&key) else {
+            break;
+        };
+        let expense = Expense::Custom {
+            name: name.clone(),
+            amount: ex
+                .get(&format!("{key} Amount"))
+                .and_then(|amount| amount.parse::<Money>().ok()),
+            group: ex
+                .get(&format!("{key} Group"))
+                .filter(|group| !group.is_empty())
+                .cloned(),
+        };
+        let frequency = ex
+            .get(&format!("{key} Frequency"))
+            .and_then(|frequency| frequency.parse::<ExpenseFrequency>().ok())
+            .unwrap_or_default();
+        expense_items.push(ExpenseItem::new(expense, frequency));
+    }
+
+    // likewise the standard deductions come first, followed by any custom ones
+    let mut pretax_deductions = vec![
+        PreTaxDeduction::Medical(de["Medical"].parse::<DeductionAmount>().ok()),
+        PreTaxDeduction::Dental(de["Dental"].parse::<DeductionAmount>().ok()),
+        PreTaxDeduction::Vision(de["Vision"].parse::<DeductionAmount>().ok()),
+        PreTaxDeduction::Traditional401K(de["Traditional401K"].parse::<DeductionAmount>().ok()),
+        PreTaxDeduction::HSA(de["HSA"].parse::<DeductionAmount>().ok()),
+        PreTaxDeduction::FSA(de["FSA"].parse::<DeductionAmount>().ok()),
+    ];
+    for number in 1.. {
+        let key = format!("Custom Pre-Tax {number}");
+        let Some(name) = de.get(&key) else {
+            break;
+        };
+        pretax_deductions.push(PreTaxDeduction::Custom {
+            name: name.clone(),
+            amount: de
+                .get(&format!("{key} Amount"))
+                .and_then(|amount| amount.parse::<DeductionAmount>().ok()),
+            treatment: de
+                .get(&format!("{key} Treatment"))
+                .and_then(|treatment| treatment.parse::<TaxTreatment>().ok())
+                .unwrap_or_default(),
+        });
+    }
+
+    let mut posttax_deductions = vec![
+        PostTaxDeduction::Roth401K(de["Roth401K"].parse::<DeductionAmount>().ok()),
+        PostTaxDeduction::VoluntaryLife(de["Voluntary Life"].parse::<DeductionAmount>().ok()),
+        PostTaxDeduction::VoluntaryADD(de["Voluntary ADD"].parse::<DeductionAmount>().ok()),
+        PostTaxDeduction::VoluntarySTD(de["Voluntary STD"].parse::<DeductionAmount>().ok()),
+        PostTaxDeduction::VoluntaryLTD(de["Voluntary LTD"].parse::<DeductionAmount>().ok()),
+        PostTaxDeduction::ChildSupport(de["Child Support"].parse::<DeductionAmount>().ok()),
+        PostTaxDeduction::FederalTaxLevy(de["Federal Tax Levy"].parse::<DeductionAmount>().ok()),
+        PostTaxDeduction::StudentLoan(
+            de["Student Loan Garnishment"]
+                .parse::<DeductionAmount>()
+                .ok(),
+        ),
+        PostTaxDeduction::WageGarnishment(de["Wage Garnishment"].parse::<DeductionAmount>().ok()),
+    ];
+    for number in 1.. {
+        let key = format!("Custom Post-Tax {number}");
+        let Some(name) = de.get(&key) else {
+            break;
+        };
+        posttax_deductions.push(PostTaxDeduction::Custom {
+            name: name.clone(),
+            amount: de
+                .get(&format!("{key} Amount"))
+                .and_then(|amount| amount.parse::<DeductionAmount>().ok()),
+        });
+    }
+
+    // W-4 steps left out of the inputs are blank on the form
+    let w4_amount = |key: &str| {
+        sc.get(key)
+            .and_then(|amount| amount.parse::<Money>().ok())
+            .unwrap_or_default()
+    };
+
+    EmploymentScenario {
+        pay_basis,
+        pay_frequency: sc
+            .get("Pay Frequency")
+            .and_then(|frequency| frequency.parse::<PayFrequency>().ok())
+            .unwrap_or_default(),
+        filing_status: sc
+            .get("Filing Status")
+            .and_then(|filing_status| filing_status.parse::<FilingStatus>().ok())
+            .unwrap_or_default(),
+        w4: W4 {
+            multiple_jobs: sc
+                .get("W-4 Multiple Jobs")
+                .is_some_and(|multiple_jobs| multiple_jobs == "y"),
+            dependents_credit: w4_amount("W-4 Dependents Credit"),
+            other_income: w4_amount("W-4 Other Income"),
+            deductions: w4_amount("W-4 Deductions"),
+            extra_withholding: w4_amount("W-4 Extra Withholding"),
+        },
+        expenses: Expenses::from_items(expense_items),
+        pretax_deductions: PreTaxDeductions::new(pretax_deductions),
+        posttax_deductions: PostTaxDeductions::new(posttax_deductions),
+        ..Default::default()
+    }
 }
@@ -1,102 +1,208 @@
 //! This library contains utility functions for calculating paycheck withholdings and net income given a hypothetical hourly wage and weekly working hours. The idea is pretty much like the "Sample Paycheck" tool found in the [Paycom](https://www.paycom.com/software/employee-self-service/) employee portal, but aimed at having a little more functionality and customization.
 //!
-//! The entire library was developed with the perspective of an hourly paid employee in mind, focusing on bi-weekly paychecks as the standard pay period to simulate how employees typically view and plan their income.
+//! The entire library was developed with the perspective of an hourly paid employee in mind, focusing on bi-weekly paychecks as the default pay period to simulate how employees typically view and plan their income. Weekly, semi-monthly and monthly paychecks are also supported through `PayFrequency`.
 //!
 //! The primary question this library aims to answer is: "Given an hourly wage and number of hours worked per week, what would my net paycheck be after taxes and deductions?"
 //!
-//! The secondary question this library aims to answer is: "Given a total monthly expenses amount and hourly wage, how many hours would I need to work to cover my expenses with "x" amount left over after taxes and deductions?" (this will be implemented in a future version).
+//! The secondary question this library aims to answer is: "Given a total monthly expenses amount and hourly wage, how many hours would I need to work to cover my expenses with "x" amount left over after taxes and deductions?" This is answered by `EmploymentScenario::solve_hours_per_week`.
 //!
 //! The library is structured into several modules:
 //! - `withholdings`: Contains functions to estimate federal tax withholdings, Social Security, and Medicare deductions.
 //! - `deductions`: Defines structures and functions for handling pre-tax and post-tax deductions.
-//! - `income`: Contains functions to calculate gross paycheck based on hourly wage and hours worked.
+//! - `w4`: Defines the Form W-4 (2020 and later) inputs used by the IRS Publication 15-T percentage method.
+//! - `breakdown`: Defines the itemized `PaycheckBreakdown` (paystub view) returned by a paycheck calculation.
+//! - `comparison`: Compares several employment scenarios side by side, with the difference of each from a baseline scenario.
+//! - `garnishments`: Limits garnishment orders (child support, federal tax levies, student loans and creditors) to the Consumer Credit Protection Act limits, in priority order.
+//! - `income`: Contains the `PayBasis` (hourly or salaried) and functions to calculate gross paycheck from it.
 //! - `expenses`: Defines structures and functions for managing monthly expenses.
 //! - `constants`: Contains tax and time related constants necessary for calculations.
+//! - `contributions`: Caps 401(k), HSA and FSA deductions at their annual limits and shows in which pay period each limit is reached.
+//! - `employer`: Defines the employer's 401(k) match, HSA contribution and paid premiums, and the total compensation they add up to.
+//! - `money`: Defines the exact `Money` type (integer cents) used for every dollar amount, with explicit rounding modes.
+//! - `projection`: Contains the calendar aware month-by-month projection of net income vs. expenses, built from every pay date in the year.
+//! - `report`: Renders the calculation results as text, JSON (stable schema), CSV or Markdown tables.
+//! - `solver`: Solves a scenario backwards, e.g. the minimum hours per week to cover expenses with a target amount left over.
+//! - `tax_year`: Defines data-driven tax year tables (brackets, standard deductions, FICA rates). 2026 is built in and other years can be loaded from TOML/JSON files.
 //! - `interaction`: Contains functions for interacting with the user to receive input for employment scenario.
 //! - `utils`: Contains utility functions for rounding and formatting output.
 //!
-//! A CLI tool has been added to this project to allow users to interact with the library and input their own employment scenarios, deductions, and expenses to calculate their net paycheck and compare it to their monthly expenses. There is only 1 command that starts a user interaction flow to gather the necessary inputs and then outputs the calculated net paycheck and comparison of monthly expenses to monthly income.
+//! A CLI tool has been added to this project to allow users to interact with the library and input their own employment scenarios, deductions, and expenses to calculate their net paycheck and compare it to their monthly expenses. The `start` command starts a user interaction flow to gather the necessary inputs and then outputs the calculated net paycheck and comparison of monthly expenses to monthly income. The `calc` command takes the same inputs as command-line flags instead, for shell scripts and CI jobs.
 //!
 //! Run CLI: 'check-paycheck start' or 'cargo run -- start'
 //!
+//! Run CLI from a saved scenario file: 'check-paycheck run scenarios/example.toml'
+//!
+//! Run CLI without prompts: 'check-paycheck calc --rate 25 --hours 45 --pretax medical=100 --expense housing=1500'
+//!
+//! Compare job offers side by side: 'check-paycheck compare current.toml offer.toml --scenario "--rate 30 --hours 40"'
+//!
 //! (future updates, improvements, and functionality planned)
 //!
 //!
+pub mod breakdown;
+pub mod comparison;
 pub mod constants;
+pub mod contributions;
 pub mod deductions;
+pub mod employer;
 pub mod expenses;
+pub mod garnishments;
 pub mod income;
 pub mod interaction;
+pub mod money;
+pub mod projection;
+pub mod report;
+pub mod solver;
+pub mod tax_year;
+#[cfg(test)]
+mod test_utils;
 pub mod utils;
+pub mod w4;
 pub mod withholdings;
 
+pub use crate::breakdown::*;
+pub use crate::comparison::Comparison;
 pub use crate::constants::*;
+pub use crate::contributions::{
+    ContributionPlan, ContributionSchedule, ContributionTotals, HsaCoverage, LimitReached,
+};
 pub use crate::deductions::*;
+pub use crate::employer::{
+    EmployerContributions, MatchTier, MissedMatch, TotalCompensation, sort_match_tiers,
+};
 pub use crate::expenses::*;
+pub use crate::garnishments::{GarnishmentKind, GarnishmentLine, SupportOrder};
 pub use crate::income::*;
 pub use crate::interaction::*;
+pub use crate::money::*;
+pub use crate::projection::{IncomeProjection, MonthProjection};
+pub use crate::report::{OutputFormat, Report};
+pub use crate::tax_year::*;
 pub use crate::utils::*;
+pub use crate::w4::*;
 pub use crate::withholdings::*;
 
-/// Represents an employment scenario with hourly rate, hours worked per week, filing status, and deductions.
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// Represents an employment scenario with pay basis (hourly rate or annual salary and hours worked per week), filing status, and deductions.
 /// Possible deductions avaialable are defined in the `deductions` module.
+/// `new` creates an hourly scenario; use `with_pay_basis` for a salaried employee.
+/// `pay_frequency` sets how often paychecks are issued (defaults to bi-weekly) and drives the gross pay, withholding annualization and monthly income.
+/// The scenario is calculated with the built-in 2026 tax year unless `tax_year` is set to another `TaxYear` table.
+/// `year_to_date_wages` holds the wages already paid this calendar year before the paycheck being calculated (defaults to $0), so Social Security stops once the wage base is reached and Additional Medicare Tax starts once wages pass $200,000.
+/// Traditional and Roth 401(k), HSA and FSA deductions stop at their annual limits from the `tax_year` table: `year_to_date_contributions` holds the contributions already made this year (defaults to none), `age` (the age reached by the end of the year, if known) adds catch-up contributions, and `hsa_coverage` selects the self-only or family HSA limit.
+/// `employer` holds the employer's 401(k) match, HSA contribution and paid premiums (defaults to none), which never change the paycheck but add up to `total_compensation`.
+/// Garnishment orders among the post-tax deductions are withheld up to their limits in the `garnishments` module, where `support_order` sets the child support limit.
+/// `w4` holds Steps 2 through 4 of the employee's Form W-4 (defaults to a W-4 with only Step 1 filled out) for the Publication 15-T percentage method.
 ///
 /// # Example
 /// ```
 /// use paycheck_utils::*;
 ///
 /// let new_job_scenario = EmploymentScenario::new(
-///     30.0, // hourly rate
+///     Money::new(30, 0), // hourly rate
 ///     40.0, // hours per week
 ///     FilingStatus::Single, // filing status
 ///     PreTaxDeductions::new(vec![
-///         PreTaxDeduction::Medical(Some(150.0)),
-///         PreTaxDeduction::Dental(Some(50.0)),
-///         PreTaxDeduction::Vision(Some(15.0)),
-///         PreTaxDeduction::Traditional401K(Some(200.0)),
+///         PreTaxDeduction::Medical(Some(Money::new(150, 0).into())),
+///         PreTaxDeduction::Dental(Some(Money::new(50, 0).into())),
+///         PreTaxDeduction::Vision(Some(Money::new(15, 0).into())),
+///         PreTaxDeduction::Traditional401K(Some(Money::new(200, 0).into())),
 ///     ]), // pre-tax deductions
-///     PostTaxDeductions::new(vec![PostTaxDeduction::Roth401K(Some(100.0))]), // post-tax deductions
+///     PostTaxDeductions::new(vec![PostTaxDeduction::Roth401K(Some(Money::new(100, 0).into()))]), // post-tax deductions
 ///     Expenses::new(vec![]) // expenses
 /// );
+///
+/// let salaried_scenario = EmploymentScenario::with_pay_basis(
+///     PayBasis::Salary {
+///         annual_salary: Money::new(62_400, 0),
+///         hours_per_week: 40.0,
+///         exempt: true,
+///     },
+///     FilingStatus::Single,
+///     PreTaxDeductions::default(),
+///     PostTaxDeductions::default(),
+///     Expenses::default(),
+/// );
 /// ```
 ///
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct EmploymentScenario {
-    pub hourly_rate: f32,
-    pub hours_per_week: f32,
+    pub pay_basis: PayBasis,
+    pub pay_frequency: PayFrequency,
     pub filing_status: FilingStatus,
     pub pretax_deductions: PreTaxDeductions,
     pub posttax_deductions: PostTaxDeductions,
     pub expenses: Expenses,
+    #[serde(with = "tax_year::year_or_table")]
+    pub tax_year: TaxYear,
+    pub year_to_date_wages: Money,
+    pub year_to_date_contributions: ContributionTotals,
+    pub age: Option<u8>,
+    pub hsa_coverage: HsaCoverage,
+    pub employer: EmployerContributions,
+    pub support_order: SupportOrder,
+    pub w4: W4,
 }
 
 impl EmploymentScenario {
+    /// Creates a scenario for an hourly employee
     pub fn new(
-        hourly_rate: f32,
+        hourly_rate: Money,
         hours_per_week: f32,
         filing_status: FilingStatus,
         pretax_deductions: PreTaxDeductions,
         posttax_deductions: PostTaxDeductions,
         expenses: Expenses,
+    ) -> Self {
+        EmploymentScenario::with_pay_basis(
+            PayBasis::Hourly {
+                rate: hourly_rate,
+                hours_per_week,
+            },
+            filing_status,
+            pretax_deductions,
+            posttax_deductions,
+            expenses,
+        )
+    }
+
+    /// Creates a scenario for any `PayBasis` (hourly or salaried)
+    pub fn with_pay_basis(
+        pay_basis: PayBasis,
+        filing_status: FilingStatus,
+        pretax_deductions: PreTaxDeductions,
+        posttax_deductions: PostTaxDeductions,
+        expenses: Expenses,
     ) -> Self {
         EmploymentScenario {
-            hourly_rate,
-            hours_per_week,
+            pay_basis,
+            pay_frequency: PayFrequency::default(),
             filing_status,
             pretax_deductions,
             posttax_deductions,
             expenses,
+            tax_year: TaxYear::default(),
+            year_to_date_wages: Money::ZERO,
+            year_to_date_contributions: ContributionTotals::default(),
+            age: None,
+            hsa_coverage: HsaCoverage::default(),
+            employer: EmployerContributions::default(),
+            support_order: SupportOrder::default(),
+            w4: W4::default(),
         }
     }
 
     /// Calculates the net paycheck based on the employment scenario's parameters.
     /// The calculations consider gross income, pre-tax deductions, federal tax withholdings, Social Security, Medicare, and post-tax deductions.
-    /// The IRS defined constants used to make calculations (such as tax rates, thresholds and standard deductions) are defined in the `constants` module.
-    /// This IRS method and flow for calculating withholdings is based on the 2026 federal tax year guidelines and can be summarized as follows:
-    ///    1. Calculate gross paycheck on hourly rate and hours worked.
+    /// The IRS defined values used to make calculations (such as tax rates, thresholds and standard deductions) come from the scenario's `tax_year` table.
+    /// This IRS method and flow for calculating withholdings can be summarized as follows:
+    ///    1. Calculate gross paycheck from the pay basis (hourly rate and hours worked, or annual salary with overtime only for non-exempt employees).
     ///    2. Subtract pre-tax deductions from gross paycheck to get adjusted gross paycheck.
-    ///    3. Calculate federal tax withholdings based on annualized adjusted gross paycheck and filing status.
-    ///    4. Calculate Social Security and Medicare withholdings based on adjusted gross paycheck.
+    ///    3. Calculate federal tax withholdings from the adjusted gross paycheck, filing status and W-4 with the IRS Publication 15-T percentage method.
+    ///    4. Calculate Social Security and Medicare withholdings based on the FICA wages: gross paycheck less the pre-tax deductions that are also exempt from FICA (see `TaxTreatment`). Social Security stops at the wage base and Additional Medicare Tax starts over $200,000, counting `year_to_date_wages`.
     ///    5. Subtract federal tax withholdings, Social Security, Medicare, and post-tax deductions from adjusted gross paycheck to get net paycheck.
     ///
     /// # Example
@@ -104,46 +210,47 @@ impl EmploymentScenario {
     /// use paycheck_utils::*;
     ///
     /// let pretax_deductions = PreTaxDeductions::new(vec![
-    ///     PreTaxDeduction::Medical(Some(100.0)),
-    ///     PreTaxDeduction::Dental(Some(50.0)),
-    ///     PreTaxDeduction::Vision(Some(25.0)),
-    ///     PreTaxDeduction::Traditional401K(Some(200.0)),
-    ///     PreTaxDeduction::HSA(Some(150.0)),
+    ///     PreTaxDeduction::Medical(Some(Money::new(100, 0).into())),
+    ///     PreTaxDeduction::Dental(Some(Money::new(50, 0).into())),
+    ///     PreTaxDeduction::Vision(Some(Money::new(25, 0).into())),
+    ///     PreTaxDeduction::Traditional401K(Some(Money::new(200, 0).into())),
+    ///     PreTaxDeduction::HSA(Some(Money::new(150, 0).into())),
     /// ]); // total = 525.0
     /// let posttax_deductions = PostTaxDeductions::new(vec![
-    ///     PostTaxDeduction::Roth401K(Some(100.0)),
-    ///     PostTaxDeduction::VoluntaryLife(Some(30.0)),
+    ///     PostTaxDeduction::Roth401K(Some(Money::new(100, 0).into())),
+    ///     PostTaxDeduction::VoluntaryLife(Some(Money::new(30, 0).into())),
     /// ]); // total = 130.0
     /// let scenario = EmploymentScenario::new(
-    ///     25.0, // hourly rate
+    ///     Money::new(25, 0), // hourly rate
     ///     45.0, // hours per week (bi-weekly paycheck = 90 hours [10 hours overtime])
     ///     FilingStatus::Single, // single filing status for standard deduction
     ///     pretax_deductions, // total = 525.0
     ///     posttax_deductions, // total = 130.0
     ///     Expenses::new(vec![
-    ///         Expense::Housing(Some(2000.0)),
-    ///         Expense::Energy(Some(300.0)),
+    ///         Expense::Housing(Some(Money::new(2000, 0))),
+    ///         Expense::Energy(Some(Money::new(300, 0))),
     ///     ]), // total = 2300.0
     /// );
     /// let net_paycheck = scenario.calculate_net_paycheck();
-    /// assert_eq!(net_paycheck, 1440.33);
+    /// assert_eq!(net_paycheck, Money::new(1425, 2));
     ///
-    /// // Explanation of calculation:
+    /// // Explanation of calculation (each withholding is rounded half up to the cent):
     /// // 1. Gross Paycheck: (25.0 * 80) + (25.0 * 10 * 1.5) = 2000.0 + 375.0 = 2375.0
     /// // 2. Adjusted Gross Paycheck: 2375.0 - 525.0 = 1850.0  (after pre-tax deductions)
-    /// // 3. Federal Withholding (annualized AGP = 1850.0 * 26 = 48100.0): Using 2026 tax brackets for Single filer:
+    /// // 3. Federal Withholding (annualized AGP = 1850.0 * 26 = 48,100.0 - 16,100.0 standard deduction = 32,000.0): Using 2026 tax brackets for Single filer:
     /// //    - 10% on first 12,400 = 12,400 * 0.10 = 1,240.0
-    /// //    - 12% on amount over 12,400 up to 50,400 = (48,100.0 - 12,400.0) * 0.12 = 4,290.0
-    /// //    - Total annual federal tax = 1,240.0 + 4,290.0 = 5,530.0
-    /// //    - Bi-weekly federal withholding = 5,530.0 / 26 = 212.69
-    /// // 4. Social Security Withholding: 1850.0 * 0.062 = 114.70
-    /// // 5. Medicare Withholding: 1850.0 * 0.0145 = 26.83
-    /// // 6. Post-Tax Deductions: 100.0 + 30.0 = 130.0
-    /// // 7. Total Deductions: 212.69 + 114.70 + 26.83 + 130.0 = 484.22
-    /// // 8. Net Paycheck: 1850.0 - 212.69 - 114.70 - 26.83 - 130.0 = 1440.33
+    /// //    - 12% on amount over 12,400 up to 50,400 = (32,000.0 - 12,400.0) * 0.12 = 2,352.0
+    /// //    - Total annual federal tax = 1,240.0 + 2,352.0 = 3,592.0
+    /// //    - Bi-weekly federal withholding = 3,592.0 / 26 = 138.15
+    /// // 4. FICA Wages: 2375.0 - 325.0 = 2050.0  (the 200.0 traditional 401(k) deferral is still subject to FICA)
+    /// // 5. Social Security Withholding: 2050.0 * 0.062 = 127.10
+    /// // 6. Medicare Withholding: 2050.0 * 0.0145 = 29.725 -> 29.73
+    /// // 7. Post-Tax Deductions: 100.0 + 30.0 = 130.0
+    /// // 8. Total Deductions: 138.15 + 127.10 + 29.73 + 130.0 = 424.98
+    /// // 9. Net Paycheck: 1850.0 - 424.98 = 1425.02
     /// ```
     /// # Returns
-    /// An `f32` representing the calculated net paycheck amount.
+    /// A `Money` amount representing the calculated net paycheck, exact to the cent.
     ///
     /// # Panics
     /// This function does not explicitly panic, but it assumes that the input values (hourly rate, hours worked, deductions) are valid and reasonable.
@@ -152,23 +259,193 @@ impl EmploymentScenario {
     /// This function does not return errors, but invalid input values may lead to incorrect calculations.
     ///
     /// # Notes
-    /// The calculations are based on the 2026 federal tax year guidelines and may need to be updated for future tax years.
-    pub fn calculate_net_paycheck(&self) -> f32 {
-        let mut gross_paycheck = determine_gross_paycheck(self.hourly_rate, self.hours_per_week);
-        let total_pretax = self.pretax_deductions.total_pretax_deductions();
-        gross_paycheck -= total_pretax;
-        let federal_withholding =
-            estimate_paycheck_federal_withholdings(gross_paycheck, self.filing_status);
-        let social_security = estimate_social_security_withholding(gross_paycheck);
-        let medicare = estimate_medicare_withholding(gross_paycheck);
-        let total_posttax = self.posttax_deductions.total_posttax_deductions();
-
-        round_2_decimals(
-            gross_paycheck - federal_withholding - social_security - medicare - total_posttax,
+    /// The calculations use the scenario's `tax_year` table, which defaults to the built-in 2026 federal tax year guidelines.
+    pub fn calculate_net_paycheck(&self) -> Money {
+        self.calculate_paycheck_breakdown().net_pay
+    }
+
+    /// Calculates the itemized paycheck for the employment scenario, following the same steps as `calculate_net_paycheck`.
+    /// Every intermediate amount (regular and overtime pay, each deduction, taxable wages, each tax) is kept in the returned `PaycheckBreakdown`.
+    ///
+    /// # Example
+    /// ```
+    /// use paycheck_utils::*;
+    ///
+    /// let scenario = EmploymentScenario::new(
+    ///     Money::new(25, 0),
+    ///     45.0,
+    ///     FilingStatus::Single,
+    ///     PreTaxDeductions::new(vec![PreTaxDeduction::Medical(Some(Money::new(100, 0).into()))]),
+    ///     PostTaxDeductions::new(vec![PostTaxDeduction::Roth401K(Some(Money::new(100, 0).into()))]),
+    ///     Expenses::default(),
+    /// );
+    /// let breakdown = scenario.calculate_paycheck_breakdown();
+    /// assert_eq!(breakdown.regular_pay, Money::new(2000, 0));
+    /// assert_eq!(breakdown.overtime_pay, Money::new(375, 0));
+    /// assert_eq!(breakdown.taxable_wages, Money::new(2275, 0));
+    /// assert_eq!(breakdown.pretax_deductions[0].name, "Medical");
+    /// assert_eq!(
+    ///     breakdown.net_pay,
+    ///     breakdown.taxable_wages - breakdown.total_taxes() - breakdown.total_posttax_deductions()
+    /// );
+    /// println!("{breakdown}"); // paystub view
+    /// ```
+    pub fn calculate_paycheck_breakdown(&self) -> PaycheckBreakdown {
+        self.calculate_paycheck_breakdown_after(
+            self.year_to_date_wages,
+            self.year_to_date_contributions,
         )
     }
 
+    /// Calculates the itemized paycheck as if `year_to_date_wages` and `year_to_date_contributions` had already been paid this calendar year
+    fn calculate_paycheck_breakdown_after(
+        &self,
+        year_to_date_wages: Money,
+        year_to_date_contributions: ContributionTotals,
+    ) -> PaycheckBreakdown {
+        self.calculate_paycheck_breakdown_for(
+            self.pay_basis.gross_pay(self.pay_frequency),
+            year_to_date_wages,
+            year_to_date_contributions,
+        )
+    }
+
+    /// Calculates the itemized paycheck for the given gross pay instead of the gross pay of the scenario's `pay_basis`
+    pub(crate) fn calculate_paycheck_breakdown_for(
+        &self,
+        gross_pay: GrossPay,
+        year_to_date_wages: Money,
+        year_to_date_contributions: ContributionTotals,
+    ) -> PaycheckBreakdown {
+        // 401(k), HSA and FSA contributions stop at their annual limits
+        let mut amounts = self.capped_deductions(gross_pay, year_to_date_contributions);
+        let pretax = self.pretax_deductions.get_pretax_deductions();
+        let pretax_deductions: Vec<DeductionLine> = pretax
+            .iter()
+            .zip(&amounts.pretax)
+            .map(|(deduction, amount)| DeductionLine {
+                name: deduction.name().to_string(),
+                amount: *amount,
+            })
+            .collect();
+        let taxable_wages = gross_pay.total() - amounts.pretax.iter().copied().sum();
+        let fica_exempt_deductions: Money = pretax
+            .iter()
+            .zip(&amounts.pretax)
+            .filter(|(deduction, _)| deduction.treatment() == TaxTreatment::FederalAndFica)
+            .map(|(_, amount)| *amount)
+            .sum();
+        let fica_wages = gross_pay.total() - fica_exempt_deductions;
+        let federal_withholding = estimate_percentage_method_withholding(
+            taxable_wages,
+            self.filing_status,
+            &self.w4,
+            &self.tax_year,
+            self.pay_frequency,
+        );
+        let social_security =
+            estimate_social_security_withholding(fica_wages, year_to_date_wages, &self.tax_year);
+        let medicare =
+            estimate_medicare_withholding(fica_wages, year_to_date_wages, &self.tax_year);
+
+        // garnishments are limited to a share of the earnings left after the taxes
+        let taxes = federal_withholding + social_security + medicare.total();
+        let garnishments = self.withhold_garnishments(
+            &mut amounts.posttax,
+            gross_pay.total() - taxes,
+            taxable_wages - taxes,
+        );
+        let posttax_deductions: Vec<DeductionLine> = self
+            .posttax_deductions
+            .get_posttax_deductions()
+            .iter()
+            .zip(&amounts.posttax)
+            .map(|(deduction, amount)| DeductionLine {
+                name: deduction.name().to_string(),
+                amount: *amount,
+            })
+            .collect();
+        let total_posttax: Money = amounts.posttax.iter().copied().sum();
+
+        PaycheckBreakdown {
+            regular_pay: gross_pay.regular,
+            overtime_pay: gross_pay.overtime,
+            gross_pay: gross_pay.total(),
+            pretax_deductions,
+            taxable_wages,
+            fica_wages,
+            federal_withholding,
+            social_security,
+            medicare,
+            posttax_deductions,
+            garnishments,
+            net_pay: taxable_wages - taxes - total_posttax,
+        }
+    }
+
+    /// Projects net income vs. expenses month by month for the calendar year of `first_pay_date`, using every actual pay date instead of an average number of paychecks per month.
+    /// Months with an extra paycheck (three bi-weekly or five weekly paychecks) show the extra net income.
+    /// `year_to_date_wages` is treated as the wages paid before `first_pay_date`, and each paycheck adds its wages, so Social Security stops and Additional Medicare Tax starts at the right paycheck.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use paycheck_utils::*;
+    ///
+    /// let scenario = EmploymentScenario::new(
+    ///     Money::new(25, 0),
+    ///     40.0,
+    ///     FilingStatus::Single,
+    ///     PreTaxDeductions::default(),
+    ///     PostTaxDeductions::default(),
+    ///     Expenses::new(vec![Expense::Housing(Some(Money::new(1500, 0)))]),
+    /// );
+    /// let projection = scenario.project_monthly_income(NaiveDate::from_ymd_opt(2026, 1, 2).unwrap());
+    /// assert_eq!(projection.pay_dates().len(), 26);
+    ///
+    /// // January and July 2026 have three Friday paychecks
+    /// let extra: Vec<u32> = projection.extra_paycheck_months().iter().map(|month| month.month).collect();
+    /// assert_eq!(extra, vec![1, 7]);
+    /// println!("{projection}"); // month-by-month table
+    /// ```
+    pub fn project_monthly_income(&self, first_pay_date: NaiveDate) -> IncomeProjection {
+        let expenses = self.expenses.total_monthly_expenses();
+        let mut year_to_date_wages = self.year_to_date_wages;
+        let mut year_to_date_contributions = self.year_to_date_contributions;
+        let mut months: Vec<MonthProjection> = (1..=12)
+            .map(|month| MonthProjection {
+                month,
+                expenses,
+                ..MonthProjection::default()
+            })
+            .collect();
+
+        for pay_date in projection::pay_dates(first_pay_date, self.pay_frequency) {
+            let breakdown = self
+                .calculate_paycheck_breakdown_after(year_to_date_wages, year_to_date_contributions);
+            year_to_date_wages += breakdown.fica_wages;
+            year_to_date_contributions += self
+                .capped_deductions(
+                    self.pay_basis.gross_pay(self.pay_frequency),
+                    year_to_date_contributions,
+                )
+                .contributions;
+
+            let month = &mut months[pay_date.month0() as usize];
+            month.pay_dates.push(pay_date);
+            month.net_income += breakdown.net_pay;
+        }
+
+        IncomeProjection {
+            year: first_pay_date.year(),
+            pay_frequency: self.pay_frequency,
+            months,
+        }
+    }
+
     /// Compares the total monthly expenses to the calculated monthly net income.
+    /// Monthly net income is the net paycheck times the average paychecks in a month for the scenario's `pay_frequency` (26/12 for bi-weekly, 52/12 for weekly, 2 for semi-monthly, 1 for monthly).
+    /// Total monthly expenses are normalized from how often each expense is paid (e.g. a weekly expense counts 52/12 times and an annual expense 1/12), see `Expenses::total_monthly_expenses`.
     /// Returns a tuple containing the monthly net income, total monthly expenses, and the difference between the two.
     /// # Example
     /// ```
@@ -177,26 +454,26 @@ impl EmploymentScenario {
     /// use paycheck_utils::*;
     ///
     /// let pretax_deductions = PreTaxDeductions::new(vec![
-    ///     PreTaxDeduction::Medical(Some(100.0)),
-    ///     PreTaxDeduction::Dental(Some(50.0)),
-    ///     PreTaxDeduction::Vision(Some(25.0)),
-    ///     PreTaxDeduction::Traditional401K(Some(200.0)),
-    ///     PreTaxDeduction::HSA(Some(150.0)),
+    ///     PreTaxDeduction::Medical(Some(Money::new(100, 0).into())),
+    ///     PreTaxDeduction::Dental(Some(Money::new(50, 0).into())),
+    ///     PreTaxDeduction::Vision(Some(Money::new(25, 0).into())),
+    ///     PreTaxDeduction::Traditional401K(Some(Money::new(200, 0).into())),
+    ///     PreTaxDeduction::HSA(Some(Money::new(150, 0).into())),
     /// ]); // total = 525.0
     /// let posttax_deductions = PostTaxDeductions::new(vec![
-    ///     PostTaxDeduction::Roth401K(Some(100.0)),
-    ///     PostTaxDeduction::VoluntaryLife(Some(30.0)),
+    ///     PostTaxDeduction::Roth401K(Some(Money::new(100, 0).into())),
+    ///     PostTaxDeduction::VoluntaryLife(Some(Money::new(30, 0).into())),
     /// ]); // total = 130.0
     /// let expenses = Expenses::new(vec![
-    ///     Expense::Housing(Some(1500.0)),
-    ///     Expense::Energy(Some(200.0)),
-    ///     Expense::Water(Some(50.0)),
-    ///     Expense::Groceries(Some(400.0)),
-    ///     Expense::Phone(Some(80.0)),
-    ///     Expense::Internet(Some(60.0)),
+    ///     Expense::Housing(Some(Money::new(1500, 0))),
+    ///     Expense::Energy(Some(Money::new(200, 0))),
+    ///     Expense::Water(Some(Money::new(50, 0))),
+    ///     Expense::Groceries(Some(Money::new(400, 0))),
+    ///     Expense::Phone(Some(Money::new(80, 0))),
+    ///     Expense::Internet(Some(Money::new(60, 0))),
     /// ]); // total = 2290.0
     /// let scenario = EmploymentScenario::new(
-    ///     25.0, // hourly rate
+    ///     Money::new(25, 0), // hourly rate
     ///     45.0, // hours per week
     ///     FilingStatus::Single, // filing status
     ///     pretax_deductions,
@@ -204,22 +481,24 @@ impl EmploymentScenario {
     ///     expenses,
     /// );
     /// let (monthly_net_income, total_monthly_expenses, difference) = scenario.compare_monthly_expenses_to_monthly_income();
-    /// assert_eq!(monthly_net_income, 2880.66);
-    /// assert_eq!(total_monthly_expenses, 2290.0);
-    /// assert_eq!(difference, 590.66);
+    /// assert_eq!(monthly_net_income, Money::new(3087, 54)); // 1425.02 * 26 / 12
+    /// assert_eq!(total_monthly_expenses, Money::new(2290, 0));
+    /// assert_eq!(difference, Money::new(797, 54));
     /// ```
     /// # Returns
     /// A tuple containing:
-    /// - `f32`: Monthly net income
-    /// - `f32`: Total monthly expenses
-    /// - `f32`: Difference between monthly net income and total monthly expenses
-    pub fn compare_monthly_expenses_to_monthly_income(&self) -> (f32, f32, f32) {
-        let monthly_net_income = self.calculate_net_paycheck() * 2.0;
+    /// - `Money`: Monthly net income
+    /// - `Money`: Total monthly expenses
+    /// - `Money`: Difference between monthly net income and total monthly expenses
+    pub fn compare_monthly_expenses_to_monthly_income(&self) -> (Money, Money, Money) {
+        let monthly_net_income = self
+            .calculate_net_paycheck()
+            .multiply(self.pay_frequency.paychecks_per_month());
         let total_monthly_expenses = self.expenses.total_monthly_expenses();
         (
-            round_2_decimals(monthly_net_income),
-            round_2_decimals(total_monthly_expenses),
-            round_2_decimals(monthly_net_income - total_monthly_expenses),
+            monthly_net_income,
+            total_monthly_expenses,
+            monthly_net_income - total_monthly_expenses,
         )
     }
 }
@@ -232,29 +511,29 @@ mod tests {
     #[test]
     fn test_calculate_net_paycheck() {
         let pretax_deductions = PreTaxDeductions::new(vec![
-            PreTaxDeduction::Medical(Some(100.0)),
-            PreTaxDeduction::Dental(Some(50.0)),
-            PreTaxDeduction::Vision(Some(25.0)),
-            PreTaxDeduction::Traditional401K(Some(200.0)),
-            PreTaxDeduction::HSA(Some(150.0)),
+            PreTaxDeduction::Medical(Some(Money::new(100, 0).into())),
+            PreTaxDeduction::Dental(Some(Money::new(50, 0).into())),
+            PreTaxDeduction::Vision(Some(Money::new(25, 0).into())),
+            PreTaxDeduction::Traditional401K(Some(Money::new(200, 0).into())),
+            PreTaxDeduction::HSA(Some(Money::new(150, 0).into())),
         ]);
         let posttax_deductions = PostTaxDeductions::new(vec![
-            PostTaxDeduction::Roth401K(Some(100.0)),
-            PostTaxDeduction::VoluntaryLife(Some(30.0)),
+            PostTaxDeduction::Roth401K(Some(Money::new(100, 0).into())),
+            PostTaxDeduction::VoluntaryLife(Some(Money::new(30, 0).into())),
         ]);
         let expenses = Expenses::new(vec![
-            Expense::Housing(Some(2000.0)),
-            Expense::Energy(Some(200.0)),
-            Expense::Water(Some(50.0)),
-            Expense::Internet(Some(60.0)),
-            Expense::Phone(Some(80.0)),
-            Expense::Vehicle(Some(300.0)),
-            Expense::VehicleInsurance(Some(150.0)),
-            Expense::VehicleGas(Some(100.0)),
-            Expense::Groceries(Some(400.0)),
+            Expense::Housing(Some(Money::new(2000, 0))),
+            Expense::Energy(Some(Money::new(200, 0))),
+            Expense::Water(Some(Money::new(50, 0))),
+            Expense::Internet(Some(Money::new(60, 0))),
+            Expense::Phone(Some(Money::new(80, 0))),
+            Expense::Vehicle(Some(Money::new(300, 0))),
+            Expense::VehicleInsurance(Some(Money::new(150, 0))),
+            Expense::VehicleGas(Some(Money::new(100, 0))),
+            Expense::Groceries(Some(Money::new(400, 0))),
         ]);
         let scenario = EmploymentScenario::new(
-            25.0,
+            Money::new(25, 0),
             45.0,
             FilingStatus::Single,
             pretax_deductions,
@@ -262,32 +541,32 @@ mod tests {
             expenses,
         );
         let net_paycheck = scenario.calculate_net_paycheck();
-        assert_eq!(net_paycheck, 1440.33);
+        assert_eq!(net_paycheck, Money::new(1425, 2));
     }
 
     #[test]
     fn test_compare_monthly_expenses_to_monthly_income() {
         let pretax_deductions = PreTaxDeductions::new(vec![
-            PreTaxDeduction::Medical(Some(100.0)),
-            PreTaxDeduction::Dental(Some(50.0)),
-            PreTaxDeduction::Vision(Some(25.0)),
-            PreTaxDeduction::Traditional401K(Some(200.0)),
-            PreTaxDeduction::HSA(Some(150.0)),
+            PreTaxDeduction::Medical(Some(Money::new(100, 0).into())),
+            PreTaxDeduction::Dental(Some(Money::new(50, 0).into())),
+            PreTaxDeduction::Vision(Some(Money::new(25, 0).into())),
+            PreTaxDeduction::Traditional401K(Some(Money::new(200, 0).into())),
+            PreTaxDeduction::HSA(Some(Money::new(150, 0).into())),
         ]);
         let posttax_deductions = PostTaxDeductions::new(vec![
-            PostTaxDeduction::Roth401K(Some(100.0)),
-            PostTaxDeduction::VoluntaryLife(Some(30.0)),
+            PostTaxDeduction::Roth401K(Some(Money::new(100, 0).into())),
+            PostTaxDeduction::VoluntaryLife(Some(Money::new(30, 0).into())),
         ]);
         let expenses = Expenses::new(vec![
-            Expense::Housing(Some(1500.0)),
-            Expense::Energy(Some(200.0)),
-            Expense::Water(Some(50.0)),
-            Expense::Groceries(Some(400.0)),
-            Expense::Phone(Some(80.0)),
-            Expense::Internet(Some(60.0)),
+            Expense::Housing(Some(Money::new(1500, 0))),
+            Expense::Energy(Some(Money::new(200, 0))),
+            Expense::Water(Some(Money::new(50, 0))),
+            Expense::Groceries(Some(Money::new(400, 0))),
+            Expense::Phone(Some(Money::new(80, 0))),
+            Expense::Internet(Some(Money::new(60, 0))),
         ]);
         let scenario = EmploymentScenario::new(
-            25.0,
+            Money::new(25, 0),
             45.0,
             FilingStatus::Single,
             pretax_deductions,
@@ -296,8 +575,368 @@ mod tests {
         );
         let (monthly_net_income, total_monthly_expenses, difference) =
             scenario.compare_monthly_expenses_to_monthly_income();
-        assert_eq!(monthly_net_income, 2880.66);
-        assert_eq!(total_monthly_expenses, 2290.0);
-        assert_eq!(difference, 590.66);
+        assert_eq!(monthly_net_income, Money::new(3087, 54));
+        assert_eq!(total_monthly_expenses, Money::new(2290, 0));
+        assert_eq!(difference, Money::new(797, 54));
+    }
+
+    #[test]
+    fn test_calculate_net_paycheck_above_social_security_wage_base() {
+        let mut scenario = EmploymentScenario::new(
+            Money::new(25, 0),
+            40.0,
+            FilingStatus::Single,
+            PreTaxDeductions::default(),
+            PostTaxDeductions::default(),
+            Expenses::default(),
+        );
+        let below_wage_base = scenario.calculate_net_paycheck();
+        scenario.year_to_date_wages = Money::new(184_500, 0);
+        let above_wage_base = scenario.calculate_net_paycheck();
+        // $2,000 gross * 6.2% Social Security is no longer withheld
+        assert_eq!(above_wage_base - below_wage_base, Money::new(124, 0));
+    }
+
+    #[test]
+    fn test_calculate_net_paycheck_with_w4() {
+        let mut scenario = EmploymentScenario::new(
+            Money::new(25, 0),
+            40.0,
+            FilingStatus::Single,
+            PreTaxDeductions::default(),
+            PostTaxDeductions::default(),
+            Expenses::default(),
+        );
+        let step_1_only = scenario.calculate_net_paycheck();
+        scenario.w4 = W4 {
+            dependents_credit: Money::new(2_000, 0),
+            extra_withholding: Money::new(20, 0),
+            ..W4::default()
+        };
+        // 2,000 / 26 = 76.92 less federal withholding, 20.00 more extra withholding
+        assert_eq!(
+            scenario.calculate_net_paycheck() - step_1_only,
+            Money::new(56, 92)
+        );
+    }
+
+    #[test]
+    fn test_calculate_paycheck_breakdown() {
+        let scenario = EmploymentScenario::new(
+            Money::new(25, 0),
+            45.0,
+            FilingStatus::Single,
+            PreTaxDeductions::new(vec![
+                PreTaxDeduction::Medical(Some(Money::new(100, 0).into())),
+                PreTaxDeduction::Dental(Some(Money::new(50, 0).into())),
+                PreTaxDeduction::Vision(Some(Money::new(25, 0).into())),
+                PreTaxDeduction::Traditional401K(Some(Money::new(200, 0).into())),
+                PreTaxDeduction::HSA(Some(Money::new(150, 0).into())),
+            ]),
+            PostTaxDeductions::new(vec![
+                PostTaxDeduction::Roth401K(Some(Money::new(100, 0).into())),
+                PostTaxDeduction::VoluntaryLife(Some(Money::new(30, 0).into())),
+            ]),
+            Expenses::default(),
+        );
+        let breakdown = scenario.calculate_paycheck_breakdown();
+        assert_eq!(breakdown.regular_pay, Money::new(2000, 0));
+        assert_eq!(breakdown.overtime_pay, Money::new(375, 0));
+        assert_eq!(breakdown.gross_pay, Money::new(2375, 0));
+        assert_eq!(breakdown.pretax_deductions.len(), 5);
+        assert_eq!(breakdown.total_pretax_deductions(), Money::new(525, 0));
+        assert_eq!(breakdown.taxable_wages, Money::new(1850, 0));
+        // the $200 traditional 401(k) deferral is still subject to Social Security and Medicare
+        assert_eq!(breakdown.fica_wages, Money::new(2050, 0));
+        assert_eq!(breakdown.federal_withholding, Money::new(138, 15));
+        assert_eq!(breakdown.social_security, Money::new(127, 10));
+        assert_eq!(breakdown.medicare.base, Money::new(29, 73));
+        assert_eq!(breakdown.medicare.additional, Money::ZERO);
+        assert_eq!(breakdown.posttax_deductions[0].name, "Roth 401(k)");
+        assert_eq!(breakdown.total_posttax_deductions(), Money::new(130, 0));
+        assert_eq!(breakdown.net_pay, Money::new(1425, 2));
+        assert_eq!(breakdown.net_pay, scenario.calculate_net_paycheck());
+    }
+
+    #[test]
+    fn test_calculate_paycheck_breakdown_custom_deductions() {
+        let scenario = EmploymentScenario::new(
+            Money::new(25, 0),
+            40.0,
+            FilingStatus::Single,
+            PreTaxDeductions::new(vec![
+                PreTaxDeduction::Custom {
+                    name: "457(b)".to_string(),
+                    amount: Some(Money::new(300, 0).into()),
+                    treatment: TaxTreatment::FederalOnly,
+                },
+                PreTaxDeduction::Custom {
+                    name: "Commuter".to_string(),
+                    amount: Some(Money::new(100, 0).into()),
+                    treatment: TaxTreatment::FederalAndFica,
+                },
+            ]),
+            PostTaxDeductions::new(vec![PostTaxDeduction::Custom {
+                name: "Union Dues".to_string(),
+                amount: Some(Money::new(40, 0).into()),
+            }]),
+            Expenses::default(),
+        );
+        let breakdown = scenario.calculate_paycheck_breakdown();
+        assert_eq!(breakdown.taxable_wages, Money::new(1600, 0));
+        // the 457(b) is only exempt from federal income tax
+        assert_eq!(breakdown.fica_wages, Money::new(1900, 0));
+        assert_eq!(breakdown.social_security, Money::new(117, 80));
+        assert_eq!(breakdown.medicare.base, Money::new(27, 55));
+        assert_eq!(breakdown.posttax_deductions[0].name, "Union Dues");
+        assert_eq!(breakdown.total_posttax_deductions(), Money::new(40, 0));
+    }
+
+    #[test]
+    fn test_calculate_paycheck_breakdown_percentage_deductions() {
+        let mut scenario = EmploymentScenario::new(
+            Money::new(25, 0),
+            45.0,
+            FilingStatus::Single,
+            PreTaxDeductions::new(vec![
+                PreTaxDeduction::Traditional401K(Some(DeductionAmount::PercentOfGross(6.0))),
+                PreTaxDeduction::HSA(Some(DeductionAmount::PercentOfRegularPay(5.0))),
+            ]),
+            PostTaxDeductions::default(),
+            Expenses::default(),
+        );
+        let breakdown = scenario.calculate_paycheck_breakdown();
+        assert_eq!(breakdown.pretax_deductions[0].amount, Money::new(142, 50)); // 6% of 2,375
+        assert_eq!(breakdown.pretax_deductions[1].amount, Money::new(100, 0)); // 5% of 2,000
+        assert_eq!(breakdown.taxable_wages, Money::new(2132, 50));
+        assert_eq!(breakdown.fica_wages, Money::new(2275, 0));
+
+        scenario.pay_basis = scenario.pay_basis.with_hours_per_week(30.0);
+        let breakdown = scenario.calculate_paycheck_breakdown();
+        assert_eq!(breakdown.pretax_deductions[0].amount, Money::new(90, 0)); // 6% of 1,500
+        assert_eq!(breakdown.pretax_deductions[1].amount, Money::new(75, 0));
+    }
+
+    #[test]
+    fn test_calculate_paycheck_breakdown_salaried() {
+        let pay_basis = |exempt| PayBasis::Salary {
+            annual_salary: Money::new(52_000, 0),
+            hours_per_week: 45.0,
+            exempt,
+        };
+        let pretax_deductions = || {
+            PreTaxDeductions::new(vec![
+                PreTaxDeduction::Medical(Some(Money::new(100, 0).into())),
+                PreTaxDeduction::Dental(Some(Money::new(50, 0).into())),
+                PreTaxDeduction::Vision(Some(Money::new(25, 0).into())),
+                PreTaxDeduction::Traditional401K(Some(Money::new(200, 0).into())),
+                PreTaxDeduction::HSA(Some(Money::new(150, 0).into())),
+            ])
+        };
+        let posttax_deductions = || {
+            PostTaxDeductions::new(vec![
+                PostTaxDeduction::Roth401K(Some(Money::new(100, 0).into())),
+                PostTaxDeduction::VoluntaryLife(Some(Money::new(30, 0).into())),
+            ])
+        };
+
+        // non-exempt salary of $52,000 over 45 hours matches $25/hour over 45 hours
+        let non_exempt = EmploymentScenario::with_pay_basis(
+            pay_basis(false),
+            FilingStatus::Single,
+            pretax_deductions(),
+            posttax_deductions(),
+            Expenses::default(),
+        );
+        let breakdown = non_exempt.calculate_paycheck_breakdown();
+        assert_eq!(breakdown.overtime_pay, Money::new(375, 0));
+        assert_eq!(breakdown.net_pay, Money::new(1425, 2));
+
+        let exempt = EmploymentScenario::with_pay_basis(
+            pay_basis(true),
+            FilingStatus::Single,
+            pretax_deductions(),
+            posttax_deductions(),
+            Expenses::default(),
+        );
+        let breakdown = exempt.calculate_paycheck_breakdown();
+        assert_eq!(breakdown.regular_pay, Money::new(2000, 0));
+        assert_eq!(breakdown.overtime_pay, Money::ZERO);
+        assert_eq!(breakdown.taxable_wages, Money::new(1475, 0));
+    }
+
+    #[test]
+    fn test_compare_monthly_expenses_to_monthly_income_by_pay_frequency() {
+        let mut scenario = EmploymentScenario::with_pay_basis(
+            PayBasis::Salary {
+                annual_salary: Money::new(60_000, 0),
+                hours_per_week: 40.0,
+                exempt: true,
+            },
+            FilingStatus::Single,
+            PreTaxDeductions::default(),
+            PostTaxDeductions::default(),
+            Expenses::default(),
+        );
+        for pay_frequency in [
+            PayFrequency::Weekly,
+            PayFrequency::BiWeekly,
+            PayFrequency::SemiMonthly,
+            PayFrequency::Monthly,
+        ] {
+            scenario.pay_frequency = pay_frequency;
+            let (monthly_net_income, _, _) = scenario.compare_monthly_expenses_to_monthly_income();
+            let expected = scenario
+                .calculate_net_paycheck()
+                .multiply(pay_frequency.periods_per_year() / 12.0);
+            assert_eq!(monthly_net_income, expected, "{:?}", pay_frequency);
+        }
+
+        // the same salary paid monthly or semi-monthly gives the same monthly net income, within a cent or two of rounding
+        scenario.pay_frequency = PayFrequency::Monthly;
+        let monthly = scenario.compare_monthly_expenses_to_monthly_income().0;
+        scenario.pay_frequency = PayFrequency::SemiMonthly;
+        let semi_monthly = scenario.compare_monthly_expenses_to_monthly_income().0;
+        assert!((monthly - semi_monthly).cents().abs() <= 2);
+    }
+
+    #[test]
+    fn test_project_monthly_income() {
+        let expenses = Expenses::new(vec![
+            Expense::Housing(Some(Money::new(1500, 0))),
+            Expense::Energy(Some(Money::new(200, 0))),
+            Expense::Water(Some(Money::new(50, 0))),
+            Expense::Groceries(Some(Money::new(400, 0))),
+            Expense::Phone(Some(Money::new(80, 0))),
+            Expense::Internet(Some(Money::new(60, 0))),
+        ]);
+        let scenario = EmploymentScenario::new(
+            Money::new(25, 0),
+            45.0,
+            FilingStatus::Single,
+            PreTaxDeductions::new(vec![
+                PreTaxDeduction::Medical(Some(Money::new(100, 0).into())),
+                PreTaxDeduction::Dental(Some(Money::new(50, 0).into())),
+                PreTaxDeduction::Vision(Some(Money::new(25, 0).into())),
+                PreTaxDeduction::Traditional401K(Some(Money::new(200, 0).into())),
+                PreTaxDeduction::HSA(Some(Money::new(150, 0).into())),
+            ]),
+            PostTaxDeductions::new(vec![
+                PostTaxDeduction::Roth401K(Some(Money::new(100, 0).into())),
+                PostTaxDeduction::VoluntaryLife(Some(Money::new(30, 0).into())),
+            ]),
+            expenses,
+        );
+        let projection =
+            scenario.project_monthly_income(NaiveDate::from_ymd_opt(2026, 1, 2).unwrap());
+
+        assert_eq!(projection.months.len(), 12);
+        let january = &projection.months[0];
+        assert_eq!(january.paychecks(), 3);
+        assert_eq!(january.net_income, Money::new(4275, 6)); // 3 * 1425.02
+        assert_eq!(january.difference(), Money::new(1985, 6));
+        let february = &projection.months[1];
+        assert_eq!(february.paychecks(), 2);
+        assert_eq!(february.difference(), Money::new(560, 4));
+
+        assert_eq!(projection.extra_paycheck_months().len(), 2);
+        assert_eq!(projection.total_net_income(), Money::new(37050, 52)); // 26 * 1425.02
+        assert_eq!(projection.total_expenses(), Money::new(27480, 0));
+    }
+
+    #[test]
+    fn test_project_monthly_income_stops_social_security_at_wage_base() {
+        let scenario = EmploymentScenario::with_pay_basis(
+            PayBasis::Salary {
+                annual_salary: Money::new(260_000, 0),
+                hours_per_week: 40.0,
+                exempt: true,
+            },
+            FilingStatus::Single,
+            PreTaxDeductions::default(),
+            PostTaxDeductions::default(),
+            Expenses::default(),
+        );
+        let projection =
+            scenario.project_monthly_income(NaiveDate::from_ymd_opt(2026, 1, 2).unwrap());
+        let first_paycheck = projection.months[0].net_income;
+        let december = &projection.months[11];
+        // $10,000 paychecks reach the $184,500 wage base in the 19th paycheck, so December paychecks withhold no Social Security
+        assert_eq!(december.paychecks(), 2);
+        assert!(december.net_income > first_paycheck.divide(3.0).multiply(2.0));
+    }
+
+    #[test]
+    fn test_scenario_file_round_trip() {
+        let mut scenario = EmploymentScenario::with_pay_basis(
+            PayBasis::Salary {
+                annual_salary: Money::new(80_000, 0),
+                hours_per_week: 45.0,
+                exempt: false,
+            },
+            FilingStatus::HeadOfHousehold,
+            PreTaxDeductions::new(vec![
+                PreTaxDeduction::Traditional401K(Some(Money::new(200, 0).into())),
+                PreTaxDeduction::FSA(None),
+            ]),
+            PostTaxDeductions::new(vec![PostTaxDeduction::VoluntaryADD(Some(
+                Money::new(5, 25).into(),
+            ))]),
+            Expenses::new(vec![Expense::VehicleGas(Some(Money::new(120, 0)))]),
+        );
+        scenario.pay_frequency = PayFrequency::SemiMonthly;
+        scenario.w4.dependents_credit = Money::new(2_000, 0);
+
+        let toml_text = toml::to_string(&scenario).unwrap();
+        assert!(toml_text.contains("tax_year = 2026"));
+        let from_toml: EmploymentScenario = toml::from_str(&toml_text).unwrap();
+        let from_json: EmploymentScenario =
+            serde_json::from_str(&serde_json::to_string(&scenario).unwrap()).unwrap();
+        for loaded in [from_toml, from_json] {
+            assert_eq!(loaded.pay_basis, scenario.pay_basis);
+            assert_eq!(loaded.pay_frequency, PayFrequency::SemiMonthly);
+            assert_eq!(loaded.filing_status, FilingStatus::HeadOfHousehold);
+            assert_eq!(loaded.w4, scenario.w4);
+            assert_eq!(
+                loaded.calculate_paycheck_breakdown(),
+                scenario.calculate_paycheck_breakdown()
+            );
+        }
+    }
+
+    #[test]
+    fn test_scenario_file_defaults_and_custom_tax_year() {
+        let mut tax_year = TaxYear {
+            year: 2027,
+            ..TaxYear::default()
+        };
+        tax_year.single.standard_deduction = Money::new(17_000, 0);
+        let scenario = EmploymentScenario {
+            tax_year: tax_year.clone(),
+            ..EmploymentScenario::default()
+        };
+        // a table that is not built in is written out in full
+        let loaded: EmploymentScenario =
+            toml::from_str(&toml::to_string(&scenario).unwrap()).unwrap();
+        assert_eq!(loaded.tax_year, tax_year);
+
+        // missing fields use the defaults, and unknown built-in years are rejected
+        let minimal: EmploymentScenario =
+            toml::from_str("[pay_basis]\ntype = \"hourly\"\nrate = 20\nhours_per_week = 40\n")
+                .unwrap();
+        assert_eq!(minimal.pay_frequency, PayFrequency::BiWeekly);
+        assert_eq!(minimal.tax_year, TaxYear::default());
+        assert!(toml::from_str::<EmploymentScenario>("tax_year = 1999").is_err());
+    }
+
+    #[test]
+    fn test_example_scenario_file() {
+        let scenario: EmploymentScenario =
+            read_from_file(std::path::Path::new("scenarios/example.toml")).unwrap();
+        assert_eq!(scenario.calculate_net_paycheck(), Money::new(1425, 2));
+        assert_eq!(
+            scenario.expenses.total_monthly_expenses(),
+            Money::new(2290, 0)
+        );
     }
 }
@@ -3,24 +3,46 @@
 //!
 //! The tool utilizes the `clap` crate for command-line argument parsing, and the `anyhow` crate for error handling. The core logic for paycheck calculation and comparison will be implemented in a separate module, which will be imported into the main CLI application.
 //!
-//! There is only 1 command for this CLI that will start the interactive dialogue for the user to be guided through inputting their employment scenario, deductions, and expenses. After confirming the inputs, the tool will perform the paycheck calculation and display the results, including the weekly net paycheck and a comparison of monthly income vs expenses.
+//! The "start" command will start the interactive dialogue for the user to be guided through inputting their employment scenario, deductions, and expenses. After confirming the inputs, the tool will perform the paycheck calculation and display the results, including the itemized paycheck and a comparison of monthly income vs expenses.
+//! At the end of the dialogue the confirmed inputs can be saved to a TOML or JSON scenario file, and the "run" command calculates a saved scenario file without re-entering it.
+//! The "calc" command takes the same scenario as command-line flags and prints the same results without any prompts, so shell scripts and CI jobs can call the tool.
+//! The "solve-hours" command takes the same flags and answers the reverse question: the minimum hours per week needed to cover the expenses with a given amount (`--surplus`) left over.
+//! The "compare" command lines up several scenarios, from files or flags, to compare job offers side by side.
+//! The "solve-rate" command answers the question for negotiating offers: the minimum hourly rate at the given hours for a target net paycheck (`--net-paycheck`) or to cover the expenses with a given amount (`--surplus`) left over.
 //!
 //! The main components of the tool include:
 //! - A `main` function that serves as the entry point for the CLI application, handling command-line arguments and orchestrating the flow of the application.
 //! - An `interaction` module that contains functions for gathering user input, confirming inputs, and converting inputs into the appropriate data structures for paycheck calculation.
 //! - A `library` module that contains the core logic for paycheck calculation and comparison, including functions for calculating net paycheck based on employment scenario and deductions, and comparing monthly income to monthly expenses.
 
-use anyhow::Result;
-use clap::{Parser, Subcommand};
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
+use clap::builder::Resettable;
+use clap::{Args, Parser, Subcommand};
 use paycheck_utils::interaction::*;
+use paycheck_utils::report::{Solution, SolveReport};
+use paycheck_utils::solver::{MAX_HOURLY_RATE, MAX_HOURS_PER_WEEK, RateTarget};
+use paycheck_utils::{
+    Comparison, EmployerContributions, EmploymentScenario, ExpenseItem, Expenses, FilingStatus,
+    HsaCoverage, MatchTier, Money, OutputFormat, PayBasis, PayFrequency, PostTaxDeduction,
+    PostTaxDeductions, PreTaxDeduction, PreTaxDeductions, Report, SupportOrder, TaxYear, W4,
+    read_from_file, sort_match_tiers, split_shell_words, write_to_file,
+};
+use std::path::PathBuf;
 
 /// A CLI tool for estimating paycheck net income and withholdings in order to compare against a given set of living expenses.
 /// This tool will allow users to input their employment scenario, including hourly rate, hours worked per week, filing status, pretax deductions, posttax deductions, and monthly expenses. The tool will then calculate the user's estimated net paycheck and compare it against their monthly expenses to help them understand their financial situation.
 ///
-/// There is only 1 command for this CLI ("start") that will start the interactive dialogue for the user to be guided through inputting their employment scenario, deductions, and expenses. After confirming the inputs, the tool will perform the paycheck calculation and display the results, including the weekly net paycheck and a comparison of monthly income vs expenses.
+/// The "start" command will start the interactive dialogue for the user to be guided through inputting their employment scenario, deductions, and expenses. After confirming the inputs, the tool will perform the paycheck calculation and display the results, including the itemized paycheck and a comparison of monthly income vs expenses.
+/// The "run" command loads the scenario from a file and the "calc" command takes the scenario as flags instead of prompts.
 /// ```
 /// Example usage:
 /// $ check-paycheck start
+/// $ check-paycheck run scenarios/example.toml
+/// $ check-paycheck calc --rate 25 --hours 45 --pretax medical=100 --expense housing=1500
+/// $ check-paycheck solve-hours --rate 25 --surplus 500 --expense housing=1500
+/// $ check-paycheck solve-rate --hours 40 --net-paycheck 1500
+/// $ check-paycheck compare current.toml offer.toml --scenario "--rate 30 --hours 40"
 /// ```
 #[derive(Parser, Debug)]
 #[command(name = "check-paycheck")]
@@ -33,22 +55,269 @@ pub struct Cli {
     /// subcommand setup to allow for future expansion of the CLI tool with additional commands
     #[command(subcommand)]
     command: Commands,
+
+    /// built-in tax year to calculate with (defaults to 2026)
+    #[arg(long, global = true, conflicts_with = "tax_year_file")]
+    tax_year: Option<u16>,
+
+    /// TOML or JSON file containing a tax year table to calculate with
+    #[arg(long, global = true)]
+    tax_year_file: Option<PathBuf>,
+
+    /// output format for the results: text, json, csv or markdown
+    #[arg(long, global = true, default_value = "text")]
+    output: OutputFormat,
+}
+
+impl Cli {
+    /// Resolve the tax year table selected by the `--tax-year` or `--tax-year-file` options
+    fn select_tax_year(&self) -> Result<TaxYear> {
+        match (&self.tax_year_file, self.tax_year) {
+            (Some(path), _) => TaxYear::from_file(path),
+            (None, Some(year)) => TaxYear::builtin(year).with_context(|| {
+                format!(
+                    "tax year {year} is not built in (built-in years: {:?}), use --tax-year-file to load it",
+                    TaxYear::BUILTIN_YEARS
+                )
+            }),
+            (None, None) => Ok(TaxYear::default()),
+        }
+    }
 }
 
-/// Subcommands for the CLI tool, currently only includes "start" to initiate the interactive dialogue for user input but
-/// allows for future expansion with additional commands as needed
+/// Subcommands for the CLI tool: "start" to initiate the interactive dialogue for user input, "run" to calculate a scenario saved in a file, "calc" to calculate a scenario given entirely by command-line flags, "solve-hours" to find the hours per week needed to cover expenses, and "solve-rate" to find the hourly rate needed for a target net paycheck or to cover expenses, and "compare" to compare several scenarios side by side
 #[derive(Subcommand, Debug)]
 pub enum Commands {
     /// start a dialogue to input employment scenario, deductions, and expenses
-    Start,
+    Start {
+        /// first pay date of the year (YYYY-MM-DD) to also show a month-by-month projection of every paycheck in the year
+        #[arg(long)]
+        first_pay_date: Option<NaiveDate>,
+    },
+    /// calculate a scenario loaded from a TOML or JSON scenario file (see scenarios/example.toml)
+    Run {
+        /// scenario file (.toml or .json)
+        file: PathBuf,
+
+        /// first pay date of the year (YYYY-MM-DD) to also show a month-by-month projection of every paycheck in the year
+        #[arg(long)]
+        first_pay_date: Option<NaiveDate>,
+    },
+    /// calculate a scenario given by flags, without any prompts (for shell scripts and CI jobs)
+    Calc {
+        #[command(flatten)]
+        scenario: ScenarioArgs,
+
+        /// first pay date of the year (YYYY-MM-DD) to also show a month-by-month projection of every paycheck in the year
+        #[arg(long)]
+        first_pay_date: Option<NaiveDate>,
+    },
+    /// solve for the minimum hours per week that cover the monthly expenses with a target amount left over (the --hours flag is ignored)
+    SolveHours {
+        #[command(flatten)]
+        scenario: ScenarioArgs,
+
+        /// amount that should be left over each month after expenses
+        #[arg(long, default_value = "0")]
+        surplus: Money,
+    },
+    /// solve for the minimum hourly rate at the given --hours that reaches a target net paycheck, or that covers the monthly expenses with a target amount left over
+    // the pay rate is solved for, so the rate and salary flags are not needed
+    #[command(
+        mut_arg("rate", |arg| arg.required_unless_present(Resettable::Reset).hide(true)),
+        mut_arg("salary", |arg| arg.hide(true)),
+        mut_arg("exempt", |arg| arg.hide(true))
+    )]
+    SolveRate {
+        #[command(flatten)]
+        scenario: ScenarioArgs,
+
+        /// net pay each paycheck should reach
+        #[arg(long, conflicts_with = "surplus")]
+        net_paycheck: Option<Money>,
+
+        /// amount that should be left over each month after expenses (the default target, with 0 left over)
+        #[arg(long)]
+        surplus: Option<Money>,
+    },
+    /// compare several scenarios side by side, with the difference of each from a baseline scenario
+    Compare {
+        /// scenario files (.toml or .json), named by their file name without the extension
+        files: Vec<PathBuf>,
+
+        /// a scenario given by the "calc" flags in one quoted argument, with values containing spaces quoted inside it, repeatable (e.g. --scenario "--rate 30 --expense 'custom:Student Loan=300'"), named scenario-1, scenario-2, ...
+        #[arg(long = "scenario", value_name = "FLAGS", allow_hyphen_values = true)]
+        scenarios: Vec<String>,
+
+        /// name of the scenario the others are compared against (defaults to the first scenario)
+        #[arg(long)]
+        baseline: Option<String>,
+    },
+}
+
+/// The "calc" flags of one `compare --scenario` argument
+#[derive(Parser, Debug)]
+#[command(name = "--scenario", no_binary_name = true)]
+struct ScenarioFlags {
+    #[command(flatten)]
+    scenario: ScenarioArgs,
+}
+
+/// Command-line flags describing an employment scenario
+/// ```
+/// Example usage:
+/// $ check-paycheck calc --rate 25 --hours 45 --pretax medical=100 --pretax 401k=200 --posttax roth401k=100 --expense housing=1500
+/// ```
+#[derive(Args, Debug)]
+pub struct ScenarioArgs {
+    /// hourly pay rate
+    #[arg(long, required_unless_present = "salary", conflicts_with = "salary")]
+    rate: Option<Money>,
+
+    /// annual salary (instead of an hourly rate)
+    #[arg(long)]
+    salary: Option<Money>,
+
+    /// salaried employee is exempt from overtime
+    #[arg(long, requires = "salary")]
+    exempt: bool,
+
+    /// hours worked per week
+    #[arg(long, default_value_t = 40.0)]
+    hours: f32,
+
+    /// pay frequency: weekly, biweekly, semimonthly or monthly
+    #[arg(long, default_value = "biweekly")]
+    pay_frequency: PayFrequency,
+
+    /// filing status: single, married-jointly, married-separately or head-of-household
+    #[arg(long, default_value = "single")]
+    filing_status: FilingStatus,
+
+    /// W-4 Step 2(c): the multiple jobs or spouse works box is checked
+    #[arg(long)]
+    w4_multiple_jobs: bool,
+
+    /// W-4 Step 3: annual credit for dependents and other credits
+    #[arg(long, default_value = "0")]
+    w4_dependents_credit: Money,
+
+    /// W-4 Step 4(a): other annual income not from jobs
+    #[arg(long, default_value = "0")]
+    w4_other_income: Money,
+
+    /// W-4 Step 4(b): annual deductions beyond the standard deduction
+    #[arg(long, default_value = "0")]
+    w4_deductions: Money,
+
+    /// W-4 Step 4(c): extra withholding per paycheck
+    #[arg(long, default_value = "0")]
+    w4_extra_withholding: Money,
+
+    /// pre-tax deduction per paycheck as an amount, a percentage of gross pay (6%) or of regular pay (6%regular), repeatable (kinds: medical, dental, vision, traditional401k, hsa, fsa, or custom:NAME[:federal-only|federal-and-fica] for any other deduction, federal-and-fica by default)
+    #[arg(long = "pretax", value_name = "KIND=AMOUNT|PERCENT%")]
+    pretax_deductions: Vec<PreTaxDeduction>,
+
+    /// post-tax deduction per paycheck as an amount or a percentage of pay, repeatable (kinds: roth401k, life, add, std, ltd, or custom:NAME for any other deduction), or a garnishment order withheld up to its legal limit (kinds: childsupport, taxlevy, studentloan, garnishment for a creditor)
+    #[arg(long = "posttax", value_name = "KIND=AMOUNT|PERCENT%")]
+    posttax_deductions: Vec<PostTaxDeduction>,
+
+    /// age reached by the end of the year, for 401(k) catch-up contributions from age 50 and HSA catch-up contributions from age 55
+    #[arg(long)]
+    age: Option<u8>,
+
+    /// HSA coverage for the HSA contribution limit: self-only or family
+    #[arg(long, default_value = "self-only")]
+    hsa_coverage: HsaCoverage,
+
+    /// the employee supports a second family (another spouse or child), which lowers the child support garnishment limit from 60% to 50% of disposable earnings
+    #[arg(long)]
+    supports_second_family: bool,
+
+    /// child support is more than 12 weeks in arrears, which raises the child support garnishment limit by 5% of disposable earnings
+    #[arg(long)]
+    support_arrears_over_12_weeks: bool,
+
+    /// employer 401(k) match formula as MATCH%@UP_TO% tiers of pay, comma separated or repeatable (e.g. 100%@4% for 100% of the first 4%, or 100%@3%,50%@5% for 100% of the first 3% and 50% of the next 2%)
+    #[arg(long, value_name = "MATCH%@UP_TO%", value_delimiter = ',')]
+    employer_match: Vec<MatchTier>,
+
+    /// employer HSA contribution for the year
+    #[arg(long, default_value = "0")]
+    employer_hsa: Money,
+
+    /// employer-paid share of insurance premiums per paycheck
+    #[arg(long, default_value = "0")]
+    employer_premiums: Money,
+
+    /// expense, repeatable (kinds: housing, energy, water, gas, internet, phone, vehicle, vehicle-insurance, vehicle-gas, groceries, or custom:NAME[:GROUP] for any other expense), monthly unless followed by /weekly, /biweekly, /quarterly, /semiannual, /annual or /onetime
+    #[arg(long = "expense", value_name = "KIND=AMOUNT[/FREQUENCY]")]
+    expenses: Vec<ExpenseItem>,
+}
+
+impl ScenarioArgs {
+    /// Build the employment scenario described by the flags
+    fn to_scenario(&self) -> Result<EmploymentScenario> {
+        let pay_basis = match self.salary {
+            Some(annual_salary) => PayBasis::Salary {
+                annual_salary,
+                hours_per_week: self.hours,
+                exempt: self.exempt,
+            },
+            None => PayBasis::Hourly {
+                rate: self.rate.unwrap_or_default(),
+                hours_per_week: self.hours,
+            },
+        };
+        let mut scenario = EmploymentScenario::with_pay_basis(
+            pay_basis,
+            self.filing_status,
+            PreTaxDeductions::new(self.pretax_deductions.clone()),
+            PostTaxDeductions::new(self.posttax_deductions.clone()),
+            Expenses::from_items(self.expenses.clone()),
+        );
+        scenario.pay_frequency = self.pay_frequency;
+        scenario.w4 = W4 {
+            multiple_jobs: self.w4_multiple_jobs,
+            dependents_credit: self.w4_dependents_credit,
+            other_income: self.w4_other_income,
+            deductions: self.w4_deductions,
+            extra_withholding: self.w4_extra_withholding,
+        };
+        scenario.age = self.age;
+        scenario.hsa_coverage = self.hsa_coverage;
+        scenario.support_order = SupportOrder {
+            supports_second_family: self.supports_second_family,
+            arrears_over_12_weeks: self.support_arrears_over_12_weeks,
+        };
+        scenario.employer = EmployerContributions {
+            match_tiers: sort_match_tiers(self.employer_match.clone())
+                .map_err(anyhow::Error::msg)
+                .context("invalid --employer-match")?,
+            hsa_seed: self.employer_hsa,
+            premiums: self.employer_premiums,
+        };
+        Ok(scenario)
+    }
+}
+
+/// Display the itemized paycheck, the monthly comparison, and the month-by-month projection when a first pay date was given, in the selected output format
+fn print_results(
+    scenario: &EmploymentScenario,
+    first_pay_date: Option<NaiveDate>,
+    output: OutputFormat,
+) {
+    let report = Report::new(scenario, first_pay_date);
+    println!("{}", report.render(output));
 }
 
-/// Main function serves as the entry point for the CLI application, handling command-line arguments and orchestrating the flow of the application. It matches on the provided subcommand and executes the corresponding logic, which currently includes starting the interactive dialogue to receive user input for employment scenario, deductions, and expenses, performing paycheck calculation based on confirmed inputs, and displaying the calculated paycheck details.
+/// Main function serves as the entry point for the CLI application, handling command-line arguments and orchestrating the flow of the application. It matches on the provided subcommand and executes the corresponding logic: either starting the interactive dialogue to receive user input for employment scenario, deductions, and expenses, or building the scenario from the "calc" flags, then performing paycheck calculation and displaying the calculated paycheck details.
 pub fn main() -> Result<()> {
     let cli = Cli::parse();
+    let tax_year = cli.select_tax_year()?;
 
     match &cli.command {
-        Commands::Start => {
+        Commands::Start { first_pay_date } => {
             // Start the interactive dialogue to receive user input for employment scenario, deductions, and expenses
             println!("\n{:-^100}", " CHECK-PAYCHECK CLI TOOL: ");
 
@@ -63,23 +332,129 @@ pub fn main() -> Result<()> {
             println!("\n{:-^100}", "-");
 
             // create a scenario from user inputs
-            let scenario = get_user_input();
+            let mut scenario = get_user_input();
+            scenario.tax_year = tax_year;
 
-            // Perform paycheck calculation based on confirmed inputs
-            let net_paycheck = scenario.calculate_net_paycheck();
-            let comparison = scenario.compare_monthly_expenses_to_monthly_income();
+            print_results(&scenario, *first_pay_date, cli.output);
 
-            // Display the calculated paycheck details
-
-            println!("\n{:^100}", "--- Paycheck Calculation Results ---");
-            println!("\nWeekly Net Paycheck: ${:.2}", net_paycheck);
-            println!(
-                "Monthly Net Income: ${:.2}\nTotal Monthly Expenses: ${:.2}\nDifference: ${:.2}\n",
-                comparison.0, comparison.1, comparison.2
-            );
+            // Offer to save the confirmed inputs to a scenario file for `check-paycheck run`
+            if let Some(path) = get_save_path() {
+                write_to_file(&path, &scenario)?;
+                println!(
+                    "Saved scenario to {} (run it again with: check-paycheck run {})\n",
+                    path.display(),
+                    path.display()
+                );
+            }
 
             // Restart or exit based on user choice (future implementation)
         }
+        Commands::Run {
+            file,
+            first_pay_date,
+        } => {
+            // load the scenario from the file, with --tax-year or --tax-year-file overriding the file's tax year
+            let mut scenario: EmploymentScenario = read_from_file(file)?;
+            if cli.tax_year.is_some() || cli.tax_year_file.is_some() {
+                scenario.tax_year = tax_year;
+            }
+
+            print_results(&scenario, *first_pay_date, cli.output);
+        }
+        Commands::Calc {
+            scenario,
+            first_pay_date,
+        } => {
+            // create a scenario from the command-line flags
+            let mut scenario = scenario.to_scenario()?;
+            scenario.tax_year = tax_year;
+
+            print_results(&scenario, *first_pay_date, cli.output);
+        }
+        Commands::SolveHours { scenario, surplus } => {
+            // solve for the hours, then show the results of the scenario working those hours
+            let mut scenario = scenario.to_scenario()?;
+            scenario.tax_year = tax_year;
+
+            let hours = scenario.solve_hours_per_week(*surplus).with_context(|| {
+                format!(
+                    "cannot cover the monthly expenses with ${surplus} left over, even working {MAX_HOURS_PER_WEEK} hours per week"
+                )
+            })?;
+            scenario.pay_basis = scenario.pay_basis.with_hours_per_week(hours);
+
+            let solve_report = SolveReport {
+                solution: Solution::HoursPerWeek(hours),
+                report: Report::new(&scenario, None),
+            };
+            println!("{}", solve_report.render(cli.output));
+        }
+        Commands::SolveRate {
+            scenario,
+            net_paycheck,
+            surplus,
+        } => {
+            // solve for the rate, then show the results of the scenario paid that rate
+            let mut scenario = scenario.to_scenario()?;
+            scenario.tax_year = tax_year;
+
+            let target = match net_paycheck {
+                Some(net_paycheck) => RateTarget::NetPaycheck(*net_paycheck),
+                None => RateTarget::MonthlySurplus(surplus.unwrap_or_default()),
+            };
+            let rate = scenario.solve_hourly_rate(target).with_context(|| {
+                format!("cannot reach {target} with any hourly rate up to ${MAX_HOURLY_RATE}")
+            })?;
+            scenario.pay_basis = PayBasis::Hourly {
+                rate,
+                hours_per_week: scenario.pay_basis.hours_per_week(),
+            };
+
+            let solve_report = SolveReport {
+                solution: Solution::HourlyRate(rate),
+                report: Report::new(&scenario, None),
+            };
+            println!("{}", solve_report.render(cli.output));
+        }
+        Commands::Compare {
+            files,
+            scenarios,
+            baseline,
+        } => {
+            // load the scenario files, with --tax-year or --tax-year-file overriding each file's tax year
+            let mut named_scenarios = Vec::new();
+            for file in files {
+                let mut scenario: EmploymentScenario = read_from_file(file)?;
+                if cli.tax_year.is_some() || cli.tax_year_file.is_some() {
+                    scenario.tax_year = tax_year.clone();
+                }
+                let name = file
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+                    .unwrap_or_else(|| file.display().to_string());
+                named_scenarios.push((name, scenario));
+            }
+            // then the scenarios given by flags
+            for (index, flags) in scenarios.iter().enumerate() {
+                let words = split_shell_words(flags)
+                    .map_err(anyhow::Error::msg)
+                    .with_context(|| format!("invalid --scenario \"{flags}\""))?;
+                let flags = ScenarioFlags::try_parse_from(words)
+                    .with_context(|| format!("invalid --scenario \"{flags}\""))?;
+                let mut scenario = flags.scenario.to_scenario()?;
+                scenario.tax_year = tax_year.clone();
+                named_scenarios.push((format!("scenario-{}", index + 1), scenario));
+            }
+
+            if named_scenarios.len() < 2 {
+                bail!("compare needs at least two scenarios (scenario files or --scenario flags)");
+            }
+            let baseline = baseline
+                .clone()
+                .unwrap_or_else(|| named_scenarios[0].0.clone());
+            let comparison = Comparison::new(&named_scenarios, &baseline)?;
+            println!("{}", comparison.render(cli.output));
+        }
     }
 
     Ok(())
@@ -0,0 +1,409 @@
+//! Module for exact money arithmetic.
+//! `Money` stores an amount as a whole number of cents, so adding up paychecks, deductions and annual totals never drifts the way `f32` arithmetic does.
+//! Multiplying or dividing by a rate, hours, or a number of pay periods is the only place a fraction of a cent can appear, and each of those operations rounds back to the cent with a `RoundingMode`.
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// Scale used to turn `f32`/`f64` factors (rates, hours, pay periods) into exact integers: 6 decimal places
+const FACTOR_SCALE: i128 = 1_000_000;
+
+/// How to round an amount that falls between two cents
+/// # Variants
+/// * `HalfUp` - round to the nearest cent, halves away from zero (how payroll withholding is normally rounded)
+/// * `HalfEven` - round to the nearest cent, halves to the even cent (banker's rounding)
+/// * `Down` - drop the fraction of a cent (toward zero)
+/// * `Up` - any fraction of a cent rounds to the next cent (away from zero)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    #[default]
+    HalfUp,
+    HalfEven,
+    Down,
+    Up,
+}
+
+/// An exact amount of money in US dollars, stored as cents.
+///
+/// # Example
+/// ```
+/// use paycheck_utils::money::{Money, RoundingMode};
+///
+/// let rate = Money::from_dollars(25.0);
+/// let gross = rate.multiply(80.0);
+/// assert_eq!(gross, Money::new(2000, 0));
+///
+/// let medicare = Money::from_dollars(1850.0).multiply(0.0145); // 26.825
+/// assert_eq!(medicare, Money::from_dollars(26.83));
+/// assert_eq!(
+///     Money::from_dollars(1850.0).multiply_rounded(0.0145, RoundingMode::HalfEven),
+///     Money::from_dollars(26.82)
+/// );
+///
+/// let total: Money = [gross, -medicare].into_iter().sum();
+/// assert_eq!(total.to_string(), "1973.17");
+/// assert_eq!("1,973.17".parse::<Money>().unwrap(), total);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Money(i64);
+
+impl Money {
+    /// $0.00
+    pub const ZERO: Money = Money(0);
+
+    /// Create an amount from whole dollars and cents, e.g. `Money::new(192_979, 25)` is $192,979.25
+    pub const fn new(dollars: i64, cents: i64) -> Self {
+        Money(dollars * 100 + cents)
+    }
+
+    /// Create an amount from a number of cents
+    pub const fn from_cents(cents: i64) -> Self {
+        Money(cents)
+    }
+
+    /// Create an amount from a dollar value, rounded half up to the nearest cent
+    pub fn from_dollars(dollars: f64) -> Self {
+        Money((dollars * 100.0).round() as i64)
+    }
+
+    /// The amount in cents
+    pub const fn cents(self) -> i64 {
+        self.0
+    }
+
+    /// The amount in dollars as a floating point value (for display or interop only)
+    pub fn to_dollars(self) -> f64 {
+        self.0 as f64 / 100.0
+    }
+
+    /// Whether the amount is exactly $0.00
+    pub const fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Multiply by a rate, hours, or number of periods and round half up to the cent
+    pub fn multiply(self, factor: impl Into<f64>) -> Self {
+        self.multiply_rounded(factor, RoundingMode::default())
+    }
+
+    /// Multiply by a rate, hours, or number of periods and round to the cent with the given rounding mode
+    pub fn multiply_rounded(self, factor: impl Into<f64>, mode: RoundingMode) -> Self {
+        Money(round_ratio(
+            self.0 as i128 * to_fixed(factor.into()),
+            FACTOR_SCALE,
+            mode,
+        ))
+    }
+
+    /// Divide by a number of periods (or any other divisor) and round half up to the cent
+    pub fn divide(self, divisor: impl Into<f64>) -> Self {
+        self.divide_rounded(divisor, RoundingMode::default())
+    }
+
+    /// Divide by a number of periods (or any other divisor) and round to the cent with the given rounding mode
+    /// # Panics
+    /// Panics if the divisor is zero.
+    pub fn divide_rounded(self, divisor: impl Into<f64>, mode: RoundingMode) -> Self {
+        let divisor = to_fixed(divisor.into());
+        assert!(divisor != 0, "cannot divide money by zero");
+        Money(round_ratio(self.0 as i128 * FACTOR_SCALE, divisor, mode))
+    }
+
+    /// Round to whole dollars with the given rounding mode
+    pub fn round_to_dollar(self, mode: RoundingMode) -> Self {
+        Money(round_ratio(self.0 as i128, 100, mode) * 100)
+    }
+}
+
+/// Convert a floating point factor to a fixed point integer with 6 decimal places
+fn to_fixed(factor: f64) -> i128 {
+    (factor * FACTOR_SCALE as f64).round() as i128
+}
+
+/// Divide `numerator` by `denominator` and round the quotient to an integer with the given rounding mode
+fn round_ratio(numerator: i128, denominator: i128, mode: RoundingMode) -> i64 {
+    let (numerator, denominator) = if denominator < 0 {
+        (-numerator, -denominator)
+    } else {
+        (numerator, denominator)
+    };
+    let quotient = numerator / denominator; // truncated toward zero
+    let remainder = numerator % denominator;
+    if remainder == 0 {
+        return quotient as i64;
+    }
+    let away_from_zero = if numerator < 0 { -1 } else { 1 };
+    let twice_remainder = 2 * remainder.abs();
+    let round_away = match mode {
+        RoundingMode::Down => false,
+        RoundingMode::Up => true,
+        RoundingMode::HalfUp => twice_remainder >= denominator,
+        RoundingMode::HalfEven => {
+            twice_remainder > denominator || (twice_remainder == denominator && quotient % 2 != 0)
+        }
+    };
+    if round_away {
+        (quotient + away_from_zero) as i64
+    } else {
+        quotient as i64
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+    fn add(self, other: Money) -> Money {
+        Money(self.0 + other.0)
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+    fn sub(self, other: Money) -> Money {
+        Money(self.0 - other.0)
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+    fn neg(self) -> Money {
+        Money(-self.0)
+    }
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, other: Money) {
+        self.0 += other.0;
+    }
+}
+
+impl SubAssign for Money {
+    fn sub_assign(&mut self, other: Money) {
+        self.0 -= other.0;
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        iter.fold(Money::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Money> for Money {
+    fn sum<I: Iterator<Item = &'a Money>>(iter: I) -> Money {
+        iter.copied().sum()
+    }
+}
+
+impl fmt::Display for Money {
+    /// Formats as dollars with exactly 2 decimal places (e.g. `1440.33` or `-12.50`).
+    /// Width, alignment and the `+` flag are respected. Precision is ignored since money always has 2 decimal places.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cents = self.0.unsigned_abs();
+        let digits = format!("{}.{:02}", cents / 100, cents % 100);
+        f.pad_integral(self.0 >= 0, "", &digits)
+    }
+}
+
+/// Error returned when a string cannot be parsed as `Money`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseMoneyError(String);
+
+impl fmt::Display for ParseMoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid money amount: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseMoneyError {}
+
+impl FromStr for Money {
+    type Err = ParseMoneyError;
+
+    /// Parses amounts such as `25`, `25.5`, `-25.00`, `$1,200.50`.
+    /// More than 2 decimal places are rounded half up to the cent.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseMoneyError(s.to_string());
+        let trimmed = s.trim();
+        let (negative, unsigned) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed),
+        };
+        let cleaned: String = unsigned
+            .strip_prefix('$')
+            .unwrap_or(unsigned)
+            .chars()
+            .filter(|c| *c != ',')
+            .collect();
+        let (whole, fraction) = cleaned.split_once('.').unwrap_or((&cleaned, ""));
+        let all_digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+        if (whole.is_empty() && fraction.is_empty()) || !all_digits(whole) || !all_digits(fraction)
+        {
+            return Err(error());
+        }
+
+        let dollars: i64 = if whole.is_empty() {
+            0
+        } else {
+            whole.parse().map_err(|_| error())?
+        };
+        let mut fraction_digits = fraction.bytes().map(|b| (b - b'0') as i64);
+        let tenths = fraction_digits.next().unwrap_or(0);
+        let hundredths = fraction_digits.next().unwrap_or(0);
+        let round_up = fraction_digits.next().unwrap_or(0) >= 5;
+
+        let cents = dollars
+            .checked_mul(100)
+            .and_then(|cents| cents.checked_add(tenths * 10 + hundredths + round_up as i64))
+            .ok_or_else(error)?;
+        Ok(Money(if negative { -cents } else { cents }))
+    }
+}
+
+impl Serialize for Money {
+    /// Serialized as a number of dollars (e.g. `1440.33`)
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.to_dollars())
+    }
+}
+
+impl<'de> Deserialize<'de> for Money {
+    /// Deserialized from a number of dollars (`1440.33`, `2000`) or a string (`"1,440.33"`)
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct MoneyVisitor;
+
+        impl Visitor<'_> for MoneyVisitor {
+            type Value = Money;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a dollar amount")
+            }
+
+            fn visit_f64<E: de::Error>(self, value: f64) -> Result<Money, E> {
+                Ok(Money::from_dollars(value))
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Money, E> {
+                value
+                    .checked_mul(100)
+                    .map(Money)
+                    .ok_or_else(|| E::custom("dollar amount out of range"))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Money, E> {
+                i64::try_from(value)
+                    .map_err(|_| E::custom("dollar amount out of range"))
+                    .and_then(|value| self.visit_i64(value))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Money, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(MoneyVisitor)
+    }
+}
+
+// UNIT TESTS FOR MONEY MODULE
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rounding_modes() {
+        let amount = Money::from_cents(185_000); // $1,850.00 * 1.45% = $26.825
+        assert_eq!(amount.multiply(0.0145), Money::from_cents(2683));
+        assert_eq!(
+            amount.multiply_rounded(0.0145, RoundingMode::HalfEven),
+            Money::from_cents(2682)
+        );
+        assert_eq!(
+            amount.multiply_rounded(0.0145, RoundingMode::Down),
+            Money::from_cents(2682)
+        );
+        assert_eq!(
+            Money::from_cents(100_001).multiply_rounded(0.5, RoundingMode::Up),
+            Money::from_cents(50_001)
+        );
+        assert_eq!(
+            (-amount).multiply(0.0145),
+            Money::from_cents(-2683),
+            "half up rounds negative halves away from zero"
+        );
+    }
+
+    #[test]
+    fn test_divide() {
+        assert_eq!(Money::new(3592, 0).divide(26.0), Money::from_cents(13815));
+        assert_eq!(
+            Money::new(100, 0).divide_rounded(3.0, RoundingMode::Up),
+            Money::from_cents(3334)
+        );
+    }
+
+    #[test]
+    fn test_round_to_dollar() {
+        assert_eq!(
+            Money::from_cents(12350).round_to_dollar(RoundingMode::HalfUp),
+            Money::new(124, 0)
+        );
+        assert_eq!(
+            Money::from_cents(12350).round_to_dollar(RoundingMode::HalfEven),
+            Money::new(124, 0)
+        );
+        assert_eq!(
+            Money::from_cents(12250).round_to_dollar(RoundingMode::HalfEven),
+            Money::new(122, 0)
+        );
+    }
+
+    #[test]
+    fn test_large_amounts_are_exact() {
+        // 26 paychecks of $9,615.38 add up to exactly $249,999.88
+        let annual: Money = std::iter::repeat_n(Money::from_cents(961_538), 26).sum();
+        assert_eq!(annual, Money::from_cents(24_999_988));
+        assert_eq!(Money::from_cents(961_538).multiply(26.0), annual);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Money::from_cents(144_033).to_string(), "1440.33");
+        assert_eq!(Money::from_cents(-1250).to_string(), "-12.50");
+        assert_eq!(Money::from_cents(5).to_string(), "0.05");
+        assert_eq!(format!("{:>10}", Money::from_cents(5)), "      0.05");
+        assert_eq!(format!("{:.2}", Money::from_cents(144_033)), "1440.33");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("25".parse::<Money>(), Ok(Money::new(25, 0)));
+        assert_eq!("25.5".parse::<Money>(), Ok(Money::new(25, 50)));
+        assert_eq!("$1,200.50".parse::<Money>(), Ok(Money::new(1200, 50)));
+        assert_eq!("-0.05".parse::<Money>(), Ok(Money::from_cents(-5)));
+        assert_eq!(".995".parse::<Money>(), Ok(Money::new(1, 0)));
+        assert!("abc".parse::<Money>().is_err());
+        assert!("".parse::<Money>().is_err());
+        assert!("1.2.3".parse::<Money>().is_err());
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let amount = Money::from_cents(144_033);
+        let json = serde_json::to_string(&amount).unwrap();
+        assert_eq!(json, "1440.33");
+        assert_eq!(serde_json::from_str::<Money>(&json).unwrap(), amount);
+        assert_eq!(
+            serde_json::from_str::<Money>("2000").unwrap(),
+            Money::new(2000, 0)
+        );
+        assert_eq!(
+            serde_json::from_str::<Money>("\"1,440.33\"").unwrap(),
+            amount
+        );
+    }
+}
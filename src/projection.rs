@@ -0,0 +1,215 @@
+//! Module for calendar aware income projections.
+//! Instead of assuming the same number of paychecks every month, a projection lists every pay date in the year and groups the paychecks by calendar month, so the months with an extra paycheck (three bi-weekly or five weekly paychecks) show up in the month-by-month comparison of net income and expenses.
+
+use crate::constants::PayFrequency;
+use crate::money::Money;
+use chrono::{Datelike, Days, NaiveDate};
+use std::fmt;
+
+/// Lists every pay date from the first pay date through the end of its calendar year
+/// # Arguments
+/// * `first_pay_date` - the first pay date of the year
+/// * `pay_frequency` - how often paychecks are issued
+/// # Returns
+/// * `Vec<NaiveDate>` - pay dates in order, starting with `first_pay_date`
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use paycheck_utils::{PayFrequency, projection::pay_dates};
+///
+/// let first_pay_date = NaiveDate::from_ymd_opt(2026, 1, 2).unwrap();
+/// let dates = pay_dates(first_pay_date, PayFrequency::BiWeekly);
+/// assert_eq!(dates.len(), 26);
+/// assert_eq!(dates[1], NaiveDate::from_ymd_opt(2026, 1, 16).unwrap());
+/// ```
+/// # Notes
+/// * Weekly and bi-weekly paychecks are every 7 and 14 days after the first pay date
+/// * Semi-monthly paychecks are on the day of the month of the first pay date and the day 15 days before or after it (e.g. the 1st and 16th), except that a first pay date on the 15th, 30th or 31st pays on the 15th and the last day of the month
+/// * Monthly paychecks are on the day of the month of the first pay date
+/// * Days past the end of a shorter month fall on its last day (e.g. a 31st pay day is paid on February 28th)
+pub fn pay_dates(first_pay_date: NaiveDate, pay_frequency: PayFrequency) -> Vec<NaiveDate> {
+    let year = first_pay_date.year();
+    match pay_frequency {
+        PayFrequency::Weekly | PayFrequency::BiWeekly => {
+            let step = Days::new(pay_frequency.weeks_per_period() as u64 * 7);
+            std::iter::successors(Some(first_pay_date), |date| date.checked_add_days(step))
+                .take_while(|date| date.year() == year)
+                .collect()
+        }
+        PayFrequency::SemiMonthly | PayFrequency::Monthly => {
+            let first_day = first_pay_date.day();
+            let days = match pay_frequency {
+                // the 15th and the last day of the month
+                PayFrequency::SemiMonthly if first_day == 15 || first_day >= 30 => vec![15, 31],
+                PayFrequency::SemiMonthly if first_day > 15 => vec![first_day - 15, first_day],
+                PayFrequency::SemiMonthly => vec![first_day, first_day + 15],
+                _ => vec![first_day],
+            };
+            (first_pay_date.month()..=12)
+                .flat_map(|month| days.iter().map(move |&day| day_in_month(year, month, day)))
+                .filter(|date| *date >= first_pay_date)
+                .collect()
+        }
+    }
+}
+
+/// The given day of a month, or the last day of the month when the month is shorter
+fn day_in_month(year: i32, month: u32, day: u32) -> NaiveDate {
+    (1..=day)
+        .rev()
+        .find_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+        .expect("every month has a first day")
+}
+
+/// Net income and expenses for one calendar month of a projection
+/// # Fields
+/// * `month` - calendar month (1 = January)
+/// * `pay_dates` - pay dates falling in the month
+/// * `net_income` - total net pay of the paychecks in the month
+/// * `expenses` - total monthly expenses
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MonthProjection {
+    pub month: u32,
+    pub pay_dates: Vec<NaiveDate>,
+    pub net_income: Money,
+    pub expenses: Money,
+}
+
+impl MonthProjection {
+    /// Number of paychecks in the month
+    pub fn paychecks(&self) -> usize {
+        self.pay_dates.len()
+    }
+
+    /// Net income less expenses
+    pub fn difference(&self) -> Money {
+        self.net_income - self.expenses
+    }
+}
+
+/// Month-by-month projection of net income vs. expenses for a calendar year
+/// # Fields
+/// * `year` - calendar year of the projection
+/// * `pay_frequency` - how often paychecks are issued
+/// * `months` - one `MonthProjection` for each month, January through December
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IncomeProjection {
+    pub year: i32,
+    pub pay_frequency: PayFrequency,
+    pub months: Vec<MonthProjection>,
+}
+
+impl IncomeProjection {
+    /// Every pay date in the projection
+    pub fn pay_dates(&self) -> Vec<NaiveDate> {
+        self.months
+            .iter()
+            .flat_map(|month| month.pay_dates.iter().copied())
+            .collect()
+    }
+
+    /// Months with more paychecks than a usual month (three bi-weekly or five weekly paychecks)
+    pub fn extra_paycheck_months(&self) -> Vec<&MonthProjection> {
+        let usual_paychecks = self.pay_frequency.periods_per_year() as usize / 12;
+        self.months
+            .iter()
+            .filter(|month| month.paychecks() > usual_paychecks)
+            .collect()
+    }
+
+    /// Total net income for the year
+    pub fn total_net_income(&self) -> Money {
+        self.months.iter().map(|month| month.net_income).sum()
+    }
+
+    /// Total expenses for the year
+    pub fn total_expenses(&self) -> Money {
+        self.months.iter().map(|month| month.expenses).sum()
+    }
+
+    /// Total net income less total expenses for the year
+    pub fn total_difference(&self) -> Money {
+        self.total_net_income() - self.total_expenses()
+    }
+}
+
+impl fmt::Display for IncomeProjection {
+    /// Formats the projection as a table with one row per month and a total row
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<12}{:>10}{:>16}{:>16}{:>16}",
+            "Month", "Paychecks", "Net Income", "Expenses", "Difference"
+        )?;
+        for month in &self.months {
+            let name = NaiveDate::from_ymd_opt(self.year, month.month, 1)
+                .map(|date| date.format("%B").to_string())
+                .unwrap_or_default();
+            writeln!(
+                f,
+                "{:<12}{:>10}{:>16}{:>16}{:>16}",
+                name,
+                month.paychecks(),
+                month.net_income,
+                month.expenses,
+                month.difference()
+            )?;
+        }
+        write!(
+            f,
+            "{:<12}{:>10}{:>16}{:>16}{:>16}",
+            self.year,
+            self.pay_dates().len(),
+            self.total_net_income(),
+            self.total_expenses(),
+            self.total_difference()
+        )
+    }
+}
+
+// UNIT TESTS FOR PROJECTION MODULE
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_pay_dates_weekly_and_bi_weekly() {
+        let bi_weekly = pay_dates(date(2026, 1, 2), PayFrequency::BiWeekly);
+        assert_eq!(bi_weekly.len(), 26);
+        assert_eq!(bi_weekly.last(), Some(&date(2026, 12, 18)));
+
+        // 2026 starts on a Thursday, so a weekly Thursday paycheck lands 53 times
+        let weekly = pay_dates(date(2026, 1, 1), PayFrequency::Weekly);
+        assert_eq!(weekly.len(), 53);
+        assert_eq!(weekly.last(), Some(&date(2026, 12, 31)));
+    }
+
+    #[test]
+    fn test_pay_dates_semi_monthly_and_monthly() {
+        let semi_monthly = pay_dates(date(2026, 1, 15), PayFrequency::SemiMonthly);
+        assert_eq!(semi_monthly.len(), 24);
+        assert_eq!(semi_monthly[1], date(2026, 1, 31));
+        assert_eq!(semi_monthly[3], date(2026, 2, 28));
+
+        let from_the_16th = pay_dates(date(2026, 1, 16), PayFrequency::SemiMonthly);
+        assert_eq!(from_the_16th[0], date(2026, 1, 16));
+        assert_eq!(from_the_16th[1], date(2026, 2, 1));
+
+        let monthly = pay_dates(date(2026, 1, 31), PayFrequency::Monthly);
+        assert_eq!(monthly.len(), 12);
+        assert_eq!(monthly[1], date(2026, 2, 28));
+        assert_eq!(monthly[3], date(2026, 4, 30));
+    }
+
+    #[test]
+    fn test_pay_dates_start_mid_year() {
+        let dates = pay_dates(date(2026, 7, 10), PayFrequency::BiWeekly);
+        assert_eq!(dates[0], date(2026, 7, 10));
+        assert_eq!(dates.len(), 13);
+    }
+}
@@ -0,0 +1,752 @@
+//! Module for rendering calculation results in human and machine readable formats.
+//! A `Report` collects the itemized paycheck, the monthly income vs. expenses comparison and (optionally) the month-by-month projection of an employment scenario, and renders them as text, JSON, CSV or a Markdown table.
+//!
+//! The JSON output is a stable schema identified by `schema_version`. Fields are only added in a new schema version, never renamed or removed without bumping it. Dollar amounts are numbers (e.g. `1440.32`) and dates are `YYYY-MM-DD` strings.
+//!
+//! Schema version 1 holds:
+//! * `paycheck` - the itemized paycheck, including `fica_wages` (the wages subject to Social Security and Medicare) and `garnishments` (each garnishment order with the amount ordered, withheld and left in arrears)
+//! * `monthly` - the monthly income vs. expenses comparison
+//! * `projection` - the month-by-month projection, when a first pay date was given
+//! * `contribution_limits` - the 401(k), HSA and FSA limits reached during the year
+//! * `total_compensation` - the annual wages plus the employer's 401(k) match, HSA contribution and paid premiums, when the employer contributes
+
+use crate::EmploymentScenario;
+use crate::breakdown::{DeductionLine, PaycheckBreakdown};
+use crate::constants::PayFrequency;
+use crate::contributions::LimitReached;
+use crate::employer::TotalCompensation;
+use crate::garnishments::GarnishmentLine;
+use crate::money::Money;
+use crate::projection::{IncomeProjection, MonthProjection};
+use crate::withholdings::MedicareWithholding;
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::fmt::Write;
+use std::str::FromStr;
+
+/// Version of the JSON report schema (see the module documentation for its fields)
+pub const REPORT_SCHEMA_VERSION: u32 = 1;
+
+/// Output format for a `Report`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    /// Parses "text", "json", "csv" or "markdown" (or "md"), case insensitive
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            "markdown" | "md" => Ok(OutputFormat::Markdown),
+            _ => Err(format!(
+                "unknown output format '{s}' (expected text, json, csv or markdown)"
+            )),
+        }
+    }
+}
+
+/// Taxes withheld from a paycheck
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TaxesReport {
+    pub federal_income_tax: Money,
+    pub social_security: Money,
+    pub medicare: Money,
+    pub additional_medicare: Money,
+    pub total: Money,
+}
+
+/// Itemized paycheck section of a `Report`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PaycheckReport {
+    pub pay_frequency: PayFrequency,
+    pub regular_pay: Money,
+    pub overtime_pay: Money,
+    pub gross_pay: Money,
+    pub pretax_deductions: Vec<DeductionLine>,
+    pub total_pretax_deductions: Money,
+    pub taxable_wages: Money,
+    pub fica_wages: Money,
+    pub taxes: TaxesReport,
+    pub posttax_deductions: Vec<DeductionLine>,
+    pub total_posttax_deductions: Money,
+    pub garnishments: Vec<GarnishmentLine>,
+    pub net_pay: Money,
+}
+
+/// Monthly income vs. expenses section of a `Report`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct MonthlyReport {
+    pub net_income: Money,
+    pub expenses: Money,
+    pub difference: Money,
+}
+
+/// One month of the projection section of a `Report`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProjectionMonthReport {
+    pub month: u32,
+    pub pay_dates: Vec<NaiveDate>,
+    pub paychecks: usize,
+    pub net_income: Money,
+    pub expenses: Money,
+    pub difference: Money,
+}
+
+/// Projection section of a `Report`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProjectionReport {
+    pub year: i32,
+    pub months: Vec<ProjectionMonthReport>,
+    pub total_net_income: Money,
+    pub total_expenses: Money,
+    pub total_difference: Money,
+}
+
+/// Calculation results of an employment scenario, ready to render in any `OutputFormat`
+/// # Fields
+/// * `schema_version` - version of the JSON schema (`REPORT_SCHEMA_VERSION`)
+/// * `paycheck` - the itemized paycheck
+/// * `monthly` - monthly net income vs. expenses, using the average paychecks per month
+/// * `projection` - month-by-month projection from every pay date, when a first pay date was given (`null` in JSON otherwise)
+/// * `contribution_limits` - the annual 401(k), HSA and FSA limits reached during the year and in which pay period (see `EmploymentScenario::contribution_schedule`)
+/// * `total_compensation` - annual wages and employer contributions, when the scenario has employer contributions (`null` in JSON otherwise, see `EmploymentScenario::total_compensation`)
+/// # Example
+/// ```
+/// use paycheck_utils::*;
+///
+/// let scenario = EmploymentScenario::new(
+///     Money::new(25, 0),
+///     40.0,
+///     FilingStatus::Single,
+///     PreTaxDeductions::default(),
+///     PostTaxDeductions::default(),
+///     Expenses::new(vec![Expense::Housing(Some(Money::new(1500, 0)))]),
+/// );
+/// let report = Report::new(&scenario, None);
+/// let json: serde_json::Value = serde_json::from_str(&report.render(OutputFormat::Json)).unwrap();
+/// assert_eq!(json["schema_version"], 1);
+/// assert_eq!(json["paycheck"]["gross_pay"], 2000.0);
+/// assert_eq!(json["monthly"]["expenses"], 1500.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Report {
+    pub schema_version: u32,
+    pub paycheck: PaycheckReport,
+    pub monthly: MonthlyReport,
+    pub projection: Option<ProjectionReport>,
+    pub contribution_limits: Vec<LimitReached>,
+    pub total_compensation: Option<TotalCompensation>,
+}
+
+impl Report {
+    /// Calculate the report for a scenario, including the month-by-month projection when `first_pay_date` is given
+    pub fn new(scenario: &EmploymentScenario, first_pay_date: Option<NaiveDate>) -> Self {
+        let breakdown = scenario.calculate_paycheck_breakdown();
+        let (net_income, expenses, difference) =
+            scenario.compare_monthly_expenses_to_monthly_income();
+        let projection = first_pay_date
+            .map(|first_pay_date| scenario.project_monthly_income(first_pay_date))
+            .map(|projection| ProjectionReport::from(&projection));
+
+        Report {
+            schema_version: REPORT_SCHEMA_VERSION,
+            paycheck: PaycheckReport::new(&breakdown, scenario.pay_frequency),
+            monthly: MonthlyReport {
+                net_income,
+                expenses,
+                difference,
+            },
+            projection,
+            contribution_limits: scenario
+                .contribution_schedule(first_pay_date)
+                .limits_reached(),
+            total_compensation: (!scenario.employer.is_empty())
+                .then(|| scenario.total_compensation()),
+        }
+    }
+
+    /// Render the report in the given format
+    pub fn render(&self, format: OutputFormat) -> String {
+        match format {
+            OutputFormat::Text => self.to_text(),
+            OutputFormat::Json => {
+                serde_json::to_string_pretty(self).expect("report serializes to JSON")
+            }
+            OutputFormat::Csv => self.to_csv(),
+            OutputFormat::Markdown => self.to_markdown(),
+        }
+    }
+
+    /// One `(label, amount)` row for each amount of the paycheck, in paystub order
+    fn paycheck_rows(&self) -> Vec<(String, Money)> {
+        let paycheck = &self.paycheck;
+        let mut rows = vec![
+            ("Regular Pay".to_string(), paycheck.regular_pay),
+            ("Overtime Pay".to_string(), paycheck.overtime_pay),
+            ("Gross Pay".to_string(), paycheck.gross_pay),
+        ];
+        rows.extend(
+            paycheck
+                .pretax_deductions
+                .iter()
+                .map(|line| (format!("Pre-Tax: {}", line.name), line.amount)),
+        );
+        rows.extend([
+            ("Taxable Wages".to_string(), paycheck.taxable_wages),
+            ("FICA Wages".to_string(), paycheck.fica_wages),
+            (
+                "Federal Income Tax".to_string(),
+                paycheck.taxes.federal_income_tax,
+            ),
+            (
+                "Social Security".to_string(),
+                paycheck.taxes.social_security,
+            ),
+            ("Medicare".to_string(), paycheck.taxes.medicare),
+            (
+                "Additional Medicare".to_string(),
+                paycheck.taxes.additional_medicare,
+            ),
+        ]);
+        rows.extend(
+            paycheck
+                .posttax_deductions
+                .iter()
+                .map(|line| (format!("Post-Tax: {}", line.name), line.amount)),
+        );
+        rows.extend(
+            paycheck
+                .garnishments
+                .iter()
+                .filter(|garnishment| !garnishment.arrears.is_zero())
+                .map(|garnishment| {
+                    (
+                        format!("Garnishment Arrears: {}", garnishment.name),
+                        garnishment.arrears,
+                    )
+                }),
+        );
+        rows.push(("Net Pay".to_string(), paycheck.net_pay));
+        rows
+    }
+
+    /// Rows of the monthly comparison
+    fn monthly_rows(&self) -> [(&'static str, Money); 3] {
+        [
+            ("Monthly Net Income", self.monthly.net_income),
+            ("Total Monthly Expenses", self.monthly.expenses),
+            ("Difference", self.monthly.difference),
+        ]
+    }
+
+    /// Rows of the total compensation, when the scenario has employer contributions
+    fn compensation_rows(&self) -> Vec<(&'static str, Money)> {
+        self.total_compensation
+            .iter()
+            .flat_map(|compensation| {
+                [
+                    ("Gross Wages", compensation.gross_wages),
+                    ("Employer 401(k) Match", compensation.employer_match),
+                    ("Employer HSA Contribution", compensation.employer_hsa),
+                    ("Employer-Paid Premiums", compensation.employer_premiums),
+                    ("Total Compensation", compensation.total),
+                ]
+            })
+            .collect()
+    }
+
+    /// The employer match left behind, if any
+    fn missed_match(&self) -> Option<String> {
+        self.total_compensation
+            .and_then(|compensation| compensation.missed_match)
+            .map(|missed_match| missed_match.to_string())
+    }
+
+    /// Plain text paystub, monthly comparison and projection table (the `Display` of `PaycheckBreakdown` and `IncomeProjection`)
+    fn to_text(&self) -> String {
+        let paycheck = &self.paycheck;
+        let breakdown = PaycheckBreakdown {
+            regular_pay: paycheck.regular_pay,
+            overtime_pay: paycheck.overtime_pay,
+            gross_pay: paycheck.gross_pay,
+            pretax_deductions: paycheck.pretax_deductions.clone(),
+            taxable_wages: paycheck.taxable_wages,
+            fica_wages: paycheck.fica_wages,
+            federal_withholding: paycheck.taxes.federal_income_tax,
+            social_security: paycheck.taxes.social_security,
+            medicare: MedicareWithholding {
+                base: paycheck.taxes.medicare,
+                additional: paycheck.taxes.additional_medicare,
+            },
+            posttax_deductions: paycheck.posttax_deductions.clone(),
+            garnishments: paycheck.garnishments.clone(),
+            net_pay: paycheck.net_pay,
+        };
+
+        let mut text = format!(
+            "\n{:^100}\n\n{breakdown}\n",
+            "--- Paycheck Calculation Results ---"
+        );
+        for (label, amount) in self.monthly_rows() {
+            let _ = writeln!(text, "{label}: ${amount}");
+        }
+        for limit in &self.contribution_limits {
+            let _ = writeln!(text, "Contribution Limit: {limit}");
+        }
+        if self.total_compensation.is_some() {
+            let _ = write!(text, "\n{:^100}\n\n", "--- Annual Total Compensation ---");
+            for (label, amount) in self.compensation_rows() {
+                let _ = writeln!(text, "{label}: ${amount}");
+            }
+            if let Some(missed_match) = self.missed_match() {
+                let _ = writeln!(text, "Missed Match: {missed_match}");
+            }
+        }
+        if let Some(projection) = &self.projection {
+            let projection = IncomeProjection {
+                year: projection.year,
+                pay_frequency: paycheck.pay_frequency,
+                months: projection
+                    .months
+                    .iter()
+                    .map(|month| MonthProjection {
+                        month: month.month,
+                        pay_dates: month.pay_dates.clone(),
+                        net_income: month.net_income,
+                        expenses: month.expenses,
+                    })
+                    .collect(),
+            };
+            let _ = write!(
+                text,
+                "\n{:^100}\n\n{projection}\n",
+                "--- Monthly Projection ---"
+            );
+        }
+        text
+    }
+
+    /// CSV with a `section,name,amount` header and one row per amount
+    fn to_csv(&self) -> String {
+        let mut csv = String::from("section,name,amount\n");
+        for (label, amount) in self.paycheck_rows() {
+            let _ = writeln!(csv, "paycheck,{},{}", csv_field(&label), amount);
+        }
+        for (label, amount) in self.monthly_rows() {
+            let _ = writeln!(csv, "monthly,{},{}", csv_field(label), amount);
+        }
+        for limit in &self.contribution_limits {
+            let section = format!("contribution limit {}", limit.plan);
+            let _ = writeln!(csv, "{},Limit,{}", csv_field(&section), limit.limit);
+            let _ = writeln!(
+                csv,
+                "{},Pay Period,{}",
+                csv_field(&section),
+                limit.pay_period
+            );
+            let _ = writeln!(
+                csv,
+                "{},Not Contributed,{}",
+                csv_field(&section),
+                limit.not_contributed
+            );
+        }
+        for (label, amount) in self.compensation_rows() {
+            let _ = writeln!(csv, "compensation,{},{}", csv_field(label), amount);
+        }
+        if let Some(total_compensation) = &self.total_compensation
+            && let Some(missed_match) = total_compensation.missed_match
+        {
+            let _ = writeln!(csv, "compensation,Missed Match,{}", missed_match.missed);
+        }
+        if let Some(projection) = &self.projection {
+            for month in &projection.months {
+                let section = format!("projection {}-{:02}", projection.year, month.month);
+                let _ = writeln!(csv, "{section},Paychecks,{}", month.paychecks);
+                let _ = writeln!(csv, "{section},Net Income,{}", month.net_income);
+                let _ = writeln!(csv, "{section},Expenses,{}", month.expenses);
+                let _ = writeln!(csv, "{section},Difference,{}", month.difference);
+            }
+        }
+        csv
+    }
+
+    /// Markdown tables for the paycheck, the monthly comparison and the projection
+    fn to_markdown(&self) -> String {
+        let mut markdown = String::from("## Paycheck\n\n| Item | Amount |\n| --- | ---: |\n");
+        for (label, amount) in self.paycheck_rows() {
+            let _ = writeln!(markdown, "| {} | {} |", markdown_cell(&label), amount);
+        }
+        markdown
+            .push_str("\n## Monthly Income vs. Expenses\n\n| Item | Amount |\n| --- | ---: |\n");
+        for (label, amount) in self.monthly_rows() {
+            let _ = writeln!(markdown, "| {label} | {amount} |");
+        }
+        if !self.contribution_limits.is_empty() {
+            markdown.push_str(
+                "\n## Contribution Limits Reached\n\n| Plan | Limit | Pay Period | Pay Date | Not Contributed |\n| --- | ---: | ---: | --- | ---: |\n",
+            );
+            for limit in &self.contribution_limits {
+                let _ = writeln!(
+                    markdown,
+                    "| {} | {} | {} | {} | {} |",
+                    markdown_cell(limit.plan.name()),
+                    limit.limit,
+                    limit.pay_period,
+                    limit
+                        .pay_date
+                        .map(|date| date.to_string())
+                        .unwrap_or_default(),
+                    limit.not_contributed
+                );
+            }
+        }
+        if self.total_compensation.is_some() {
+            markdown
+                .push_str("\n## Annual Total Compensation\n\n| Item | Amount |\n| --- | ---: |\n");
+            for (label, amount) in self.compensation_rows() {
+                let _ = writeln!(markdown, "| {label} | {amount} |");
+            }
+            if let Some(missed_match) = self.missed_match() {
+                let _ = writeln!(markdown, "\n**Missed match:** {missed_match}");
+            }
+        }
+        if let Some(projection) = &self.projection {
+            markdown.push_str(
+                "\n## Monthly Projection\n\n| Month | Paychecks | Net Income | Expenses | Difference |\n| --- | ---: | ---: | ---: | ---: |\n",
+            );
+            for month in &projection.months {
+                let _ = writeln!(
+                    markdown,
+                    "| {} | {} | {} | {} | {} |",
+                    month_name(projection.year, month.month),
+                    month.paychecks,
+                    month.net_income,
+                    month.expenses,
+                    month.difference
+                );
+            }
+            let _ = writeln!(
+                markdown,
+                "| **{}** | {} | {} | {} | {} |",
+                projection.year,
+                projection
+                    .months
+                    .iter()
+                    .map(|month| month.paychecks)
+                    .sum::<usize>(),
+                projection.total_net_income,
+                projection.total_expenses,
+                projection.total_difference
+            );
+        }
+        markdown
+    }
+}
+
+/// Input found by a solver, serialized as e.g. `{"hours_per_week": 50.13}` or `{"hourly_rate": 25.0}`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Solution {
+    HoursPerWeek(f32),
+    HourlyRate(Money),
+}
+
+impl Solution {
+    /// Label of the solved input, e.g. "Hours Per Week"
+    pub fn label(&self) -> &'static str {
+        match self {
+            Solution::HoursPerWeek(_) => "Hours Per Week",
+            Solution::HourlyRate(_) => "Hourly Rate",
+        }
+    }
+
+    /// Solved value formatted for text, CSV and Markdown output
+    pub fn value(&self) -> String {
+        match self {
+            Solution::HoursPerWeek(hours) => format!("{hours:.2}"),
+            Solution::HourlyRate(rate) => rate.to_string(),
+        }
+    }
+}
+
+/// Results of a solver subcommand: the solved input and the `Report` of the scenario using it
+/// # Fields
+/// * `solution` - the solved input
+/// * `report` - report of the scenario with the solved input (its fields are flattened into the JSON object next to `solution`)
+/// # Example
+/// ```
+/// use paycheck_utils::*;
+/// use paycheck_utils::report::{Solution, SolveReport};
+///
+/// let scenario = EmploymentScenario::new(
+///     Money::new(25, 0),
+///     40.0,
+///     FilingStatus::Single,
+///     PreTaxDeductions::default(),
+///     PostTaxDeductions::default(),
+///     Expenses::default(),
+/// );
+/// let solve_report = SolveReport {
+///     solution: Solution::HoursPerWeek(40.0),
+///     report: Report::new(&scenario, None),
+/// };
+/// let json: serde_json::Value = serde_json::from_str(&solve_report.render(OutputFormat::Json)).unwrap();
+/// assert_eq!(json["solution"]["hours_per_week"], 40.0);
+/// assert_eq!(json["paycheck"]["gross_pay"], 2000.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SolveReport {
+    pub solution: Solution,
+    #[serde(flatten)]
+    pub report: Report,
+}
+
+impl SolveReport {
+    /// Render the solution followed by the report in the given format
+    pub fn render(&self, format: OutputFormat) -> String {
+        let (label, value) = (self.solution.label(), self.solution.value());
+        match format {
+            OutputFormat::Text => format!(
+                "\n{label}: {value}\n{}",
+                self.report.render(OutputFormat::Text)
+            ),
+            OutputFormat::Json => {
+                serde_json::to_string_pretty(self).expect("report serializes to JSON")
+            }
+            OutputFormat::Csv => {
+                let csv = self.report.render(OutputFormat::Csv);
+                let (header, rows) = csv.split_once('\n').unwrap_or((&csv, ""));
+                format!("{header}\nsolution,{label},{value}\n{rows}")
+            }
+            OutputFormat::Markdown => format!(
+                "## Solution\n\n| Item | Value |\n| --- | ---: |\n| {label} | {value} |\n\n{}",
+                self.report.render(OutputFormat::Markdown)
+            ),
+        }
+    }
+}
+
+impl PaycheckReport {
+    fn new(breakdown: &PaycheckBreakdown, pay_frequency: PayFrequency) -> Self {
+        PaycheckReport {
+            pay_frequency,
+            regular_pay: breakdown.regular_pay,
+            overtime_pay: breakdown.overtime_pay,
+            gross_pay: breakdown.gross_pay,
+            pretax_deductions: breakdown.pretax_deductions.clone(),
+            total_pretax_deductions: breakdown.total_pretax_deductions(),
+            taxable_wages: breakdown.taxable_wages,
+            fica_wages: breakdown.fica_wages,
+            taxes: TaxesReport {
+                federal_income_tax: breakdown.federal_withholding,
+                social_security: breakdown.social_security,
+                medicare: breakdown.medicare.base,
+                additional_medicare: breakdown.medicare.additional,
+                total: breakdown.total_taxes(),
+            },
+            posttax_deductions: breakdown.posttax_deductions.clone(),
+            total_posttax_deductions: breakdown.total_posttax_deductions(),
+            garnishments: breakdown.garnishments.clone(),
+            net_pay: breakdown.net_pay,
+        }
+    }
+}
+
+impl From<&IncomeProjection> for ProjectionReport {
+    fn from(projection: &IncomeProjection) -> Self {
+        ProjectionReport {
+            year: projection.year,
+            months: projection
+                .months
+                .iter()
+                .map(|month| ProjectionMonthReport {
+                    month: month.month,
+                    pay_dates: month.pay_dates.clone(),
+                    paychecks: month.paychecks(),
+                    net_income: month.net_income,
+                    expenses: month.expenses,
+                    difference: month.difference(),
+                })
+                .collect(),
+            total_net_income: projection.total_net_income(),
+            total_expenses: projection.total_expenses(),
+            total_difference: projection.total_difference(),
+        }
+    }
+}
+
+/// Full month name, e.g. "January"
+fn month_name(year: i32, month: u32) -> String {
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .map(|date| date.format("%B").to_string())
+        .unwrap_or_default()
+}
+
+/// Quote a CSV field when it contains a comma, quote or newline
+pub(crate) fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Escape the `|` column separator in a Markdown table cell
+pub(crate) fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|")
+}
+
+// UNIT TESTS FOR REPORT MODULE
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::ScenarioBuilder;
+    use crate::*;
+
+    /// $25/hour for 45 hours with one deduction of each kind and one expense
+    fn report_scenario() -> EmploymentScenario {
+        ScenarioBuilder::hourly(25, 45.0)
+            .pretax(vec![
+                PreTaxDeduction::Medical(Some(Money::new(100, 0).into())),
+                PreTaxDeduction::Traditional401K(Some(Money::new(200, 0).into())),
+            ])
+            .posttax(vec![PostTaxDeduction::Roth401K(Some(
+                Money::new(100, 0).into(),
+            ))])
+            .expenses(vec![Expense::Housing(Some(Money::new(1500, 0)))])
+            .build()
+    }
+
+    #[test]
+    fn test_json_schema() {
+        let report = Report::new(&report_scenario(), NaiveDate::from_ymd_opt(2026, 1, 2));
+        let json: serde_json::Value =
+            serde_json::from_str(&report.render(OutputFormat::Json)).unwrap();
+
+        assert_eq!(json["schema_version"], REPORT_SCHEMA_VERSION);
+        let paycheck = &json["paycheck"];
+        assert_eq!(paycheck["pay_frequency"], "bi-weekly");
+        assert_eq!(paycheck["gross_pay"], 2375.0);
+        assert_eq!(
+            paycheck["pretax_deductions"][1]["name"],
+            "Traditional 401(k)"
+        );
+        assert_eq!(paycheck["pretax_deductions"][1]["amount"], 200.0);
+        assert_eq!(paycheck["taxable_wages"], 2075.0);
+        for key in [
+            "federal_income_tax",
+            "social_security",
+            "medicare",
+            "additional_medicare",
+            "total",
+        ] {
+            assert!(paycheck["taxes"][key].is_number(), "{key}");
+        }
+        assert_eq!(json["monthly"]["expenses"], 1500.0);
+        assert_eq!(json["projection"]["year"], 2026);
+        assert_eq!(json["projection"]["months"][0]["paychecks"], 3);
+        assert_eq!(
+            json["projection"]["months"][0]["pay_dates"][0],
+            "2026-01-02"
+        );
+
+        let without_projection = Report::new(&report_scenario(), None);
+        let json: serde_json::Value =
+            serde_json::from_str(&without_projection.render(OutputFormat::Json)).unwrap();
+        assert!(json["projection"].is_null());
+        assert!(json["total_compensation"].is_null());
+    }
+
+    #[test]
+    fn test_total_compensation() {
+        let mut scenario = report_scenario();
+        scenario.employer = EmployerContributions {
+            match_tiers: vec!["100%@4%".parse().unwrap()],
+            hsa_seed: Money::new(500, 0),
+            premiums: Money::ZERO,
+        };
+        let report = Report::new(&scenario, None);
+        let json: serde_json::Value =
+            serde_json::from_str(&report.render(OutputFormat::Json)).unwrap();
+        let compensation = &json["total_compensation"];
+        assert_eq!(compensation["gross_wages"], 61750.0);
+        assert_eq!(compensation["employer_match"], 2470.0);
+        assert_eq!(compensation["total"], 64720.0);
+        assert!(compensation["missed_match"].is_null());
+
+        let csv = report.render(OutputFormat::Csv);
+        assert!(
+            csv.contains("compensation,Employer 401(k) Match,2470.00"),
+            "{csv}"
+        );
+        assert!(
+            report
+                .render(OutputFormat::Markdown)
+                .contains("| Total Compensation | 64720.00 |")
+        );
+    }
+
+    #[test]
+    fn test_csv_and_markdown() {
+        let report = Report::new(&report_scenario(), None);
+        let csv = report.render(OutputFormat::Csv);
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("section,name,amount"));
+        assert_eq!(lines.next(), Some("paycheck,Regular Pay,2000.00"));
+        assert!(csv.contains("paycheck,Pre-Tax: Traditional 401(k),200.00"));
+        assert!(csv.contains("monthly,Total Monthly Expenses,1500.00"));
+        assert_eq!(csv_field("a, b"), "\"a, b\"");
+
+        let markdown = report.render(OutputFormat::Markdown);
+        assert!(markdown.contains("| Gross Pay | 2375.00 |"));
+        assert!(markdown.contains("| Total Monthly Expenses | 1500.00 |"));
+    }
+
+    #[test]
+    fn test_solve_report() {
+        let solve_report = SolveReport {
+            solution: Solution::HoursPerWeek(45.0),
+            report: Report::new(&report_scenario(), None),
+        };
+        let json: serde_json::Value =
+            serde_json::from_str(&solve_report.render(OutputFormat::Json)).unwrap();
+        assert_eq!(json["schema_version"], REPORT_SCHEMA_VERSION);
+        assert_eq!(json["solution"]["hours_per_week"], 45.0);
+        assert_eq!(json["paycheck"]["gross_pay"], 2375.0);
+
+        let csv = solve_report.render(OutputFormat::Csv);
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("section,name,amount"));
+        assert_eq!(lines.next(), Some("solution,Hours Per Week,45.00"));
+        assert_eq!(lines.next(), Some("paycheck,Regular Pay,2000.00"));
+
+        assert!(
+            solve_report
+                .render(OutputFormat::Text)
+                .contains("Hours Per Week: 45.00")
+        );
+        assert!(
+            solve_report
+                .render(OutputFormat::Markdown)
+                .contains("| Hours Per Week | 45.00 |")
+        );
+    }
+
+    #[test]
+    fn test_parse_output_format() {
+        assert_eq!("JSON".parse::<OutputFormat>(), Ok(OutputFormat::Json));
+        assert_eq!("md".parse::<OutputFormat>(), Ok(OutputFormat::Markdown));
+        assert!("xml".parse::<OutputFormat>().is_err());
+    }
+}
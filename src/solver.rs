@@ -0,0 +1,397 @@
+//! Module for solving an employment scenario backwards, from a target result to the input needed to reach it.
+//! This answers the secondary question of the library: "Given a total monthly expenses amount and hourly wage, how many hours would I need to work to cover my expenses with "x" amount left over after taxes and deductions?"
+//! and its complement for negotiating offers: "Given a weekly schedule, what is the minimum hourly rate for a target net paycheck or to cover my expenses?"
+//! The gross-up solves for the gross pay that delivers an exact net paycheck, e.g. for a bonus or relocation payment.
+//!
+//! The solvers run the full net pay calculation (overtime, pre-tax deductions, progressive brackets, FICA and post-tax deductions) and binary search the input, since net pay always rises with gross pay but has no closed form inverse.
+
+use crate::EmploymentScenario;
+use crate::breakdown::PaycheckBreakdown;
+use crate::income::{GrossPay, PayBasis};
+use crate::money::Money;
+use std::fmt;
+
+/// Most hours in a week (7 days * 24 hours), the upper limit of the hours solver
+pub const MAX_HOURS_PER_WEEK: f32 = 168.0;
+
+/// Upper limit of the hourly rate solver
+pub const MAX_HOURLY_RATE: Money = Money::new(100_000, 0);
+
+/// Upper limit of the gross pay of a paycheck in the gross-up
+pub const MAX_GROSS_PAY: Money = Money::new(100_000_000, 0);
+
+/// Cents below the gross pay found by the gross-up that are checked for a lower gross pay rounding to the same net pay
+const GROSS_UP_ROUNDING_WINDOW: i64 = 100;
+
+impl EmploymentScenario {
+    /// Solves for the minimum hours worked per week that cover the monthly expenses with `target_surplus` left over each month.
+    /// The monthly net income is the same as `compare_monthly_expenses_to_monthly_income` (net paycheck times the average paychecks per month).
+    ///
+    /// # Arguments
+    /// * `target_surplus` - amount that should be left over each month after expenses
+    /// # Returns
+    /// * `Some(hours)` - the minimum weekly hours, rounded up to the hundredth of an hour
+    /// * `None` - when the target cannot be reached even working every hour of the week (`MAX_HOURS_PER_WEEK`)
+    ///
+    /// # Example
+    /// ```
+    /// use paycheck_utils::*;
+    ///
+    /// let scenario = EmploymentScenario::new(
+    ///     Money::new(25, 0),
+    ///     40.0, // replaced by the solver
+    ///     FilingStatus::Single,
+    ///     PreTaxDeductions::default(),
+    ///     PostTaxDeductions::default(),
+    ///     Expenses::new(vec![Expense::Housing(Some(Money::new(2500, 0)))]),
+    /// );
+    /// let hours = scenario.solve_hours_per_week(Money::new(500, 0)).unwrap();
+    ///
+    /// let mut solved = scenario.clone();
+    /// solved.pay_basis = solved.pay_basis.with_hours_per_week(hours);
+    /// let (_, _, difference) = solved.compare_monthly_expenses_to_monthly_income();
+    /// assert!(difference >= Money::new(500, 0));
+    /// ```
+    /// # Notes
+    /// * Hours over 40 per week are paid as overtime at time and a half, so the solved hours account for the overtime premium
+    /// * For an exempt salaried employee the hours do not change pay, so the result is either `Some(0.0)` or `None`
+    pub fn solve_hours_per_week(&self, target_surplus: Money) -> Option<f32> {
+        let mut scenario = self.clone();
+        let reaches_target = |hundredths: i64| {
+            scenario.pay_basis = self
+                .pay_basis
+                .with_hours_per_week(hundredths as f32 / 100.0);
+            let (_, _, difference) = scenario.compare_monthly_expenses_to_monthly_income();
+            difference >= target_surplus
+        };
+
+        let max_hundredths = (MAX_HOURS_PER_WEEK * 100.0) as i64;
+        smallest_reaching_target(0, max_hundredths, reaches_target)
+            .map(|hundredths| hundredths as f32 / 100.0)
+    }
+
+    /// Solves for the minimum hourly rate, at the scenario's weekly hours, that reaches the `target`.
+    /// The net paycheck is `calculate_net_paycheck`, so overtime, deductions and every withholding are included.
+    ///
+    /// # Arguments
+    /// * `target` - a net paycheck amount, or an amount left over each month after expenses (see `RateTarget`)
+    /// # Returns
+    /// * `Some(rate)` - the minimum hourly rate, to the cent
+    /// * `None` - when no hourly rate up to `MAX_HOURLY_RATE` reaches the target (e.g. zero hours per week)
+    ///
+    /// # Example
+    /// ```
+    /// use paycheck_utils::*;
+    /// use paycheck_utils::solver::RateTarget;
+    ///
+    /// let scenario = EmploymentScenario::new(
+    ///     Money::ZERO, // replaced by the solver
+    ///     40.0,
+    ///     FilingStatus::Single,
+    ///     PreTaxDeductions::default(),
+    ///     PostTaxDeductions::default(),
+    ///     Expenses::default(),
+    /// );
+    /// let rate = scenario.solve_hourly_rate(RateTarget::NetPaycheck(Money::new(1500, 0))).unwrap();
+    ///
+    /// let mut solved = scenario.clone();
+    /// solved.pay_basis = PayBasis::Hourly { rate, hours_per_week: 40.0 };
+    /// assert!(solved.calculate_net_paycheck() >= Money::new(1500, 0));
+    /// ```
+    /// # Notes
+    /// * A salaried scenario is solved as an hourly one at the same weekly hours
+    pub fn solve_hourly_rate(&self, target: RateTarget) -> Option<Money> {
+        let hours_per_week = self.pay_basis.hours_per_week();
+        let mut scenario = self.clone();
+        let reaches_target = |cents: i64| {
+            scenario.pay_basis = PayBasis::Hourly {
+                rate: Money::from_cents(cents),
+                hours_per_week,
+            };
+            target.is_reached_by(&scenario)
+        };
+
+        smallest_reaching_target(0, MAX_HOURLY_RATE.cents(), reaches_target).map(Money::from_cents)
+    }
+
+    /// Grosses up a payment: solves for the minimum gross pay whose net pay is `net_pay`, e.g. for a bonus or relocation payment that should deliver an exact net amount.
+    /// The paycheck uses the scenario's filing status, W-4, pay frequency, deductions and year-to-date wages, but not its `pay_basis`, and goes through the same withholding steps as `calculate_paycheck_breakdown`.
+    ///
+    /// # Arguments
+    /// * `net_pay` - net pay the paycheck should deliver
+    /// # Returns
+    /// * `Some(breakdown)` - the itemized paycheck at the required gross pay (paid as regular pay)
+    /// * `None` - when the net pay cannot be reached with a gross pay up to `MAX_GROSS_PAY`
+    ///
+    /// # Example
+    /// ```
+    /// use paycheck_utils::*;
+    ///
+    /// let scenario = EmploymentScenario::new(
+    ///     Money::ZERO, // not used by the gross-up
+    ///     0.0,
+    ///     FilingStatus::Single,
+    ///     PreTaxDeductions::new(vec![PreTaxDeduction::Traditional401K(Some(Money::new(200, 0).into()))]),
+    ///     PostTaxDeductions::default(),
+    ///     Expenses::default(),
+    /// );
+    /// let breakdown = scenario.gross_up(Money::new(5000, 0)).unwrap();
+    /// assert_eq!(breakdown.net_pay, Money::new(5000, 0));
+    /// assert!(breakdown.gross_pay > Money::new(6000, 0));
+    /// println!("{breakdown}"); // paystub view
+    /// ```
+    /// # Notes
+    /// * Each withholding rises by at most the gross pay increase, so net pay never skips a cent and the gross-up lands exactly on `net_pay`
+    /// * The withholdings round to the cent separately, so at a bracket edge a gross pay a few cents lower can round to the same net pay; the cents below the first gross pay found are checked so the minimum is returned
+    /// * Social Security stops at the wage base and Additional Medicare Tax starts over its threshold, counting `year_to_date_wages`
+    pub fn gross_up(&self, net_pay: Money) -> Option<PaycheckBreakdown> {
+        let breakdown_for = |cents: i64| {
+            let gross_pay = GrossPay {
+                regular: Money::from_cents(cents),
+                overtime: Money::ZERO,
+            };
+            self.calculate_paycheck_breakdown_for(
+                gross_pay,
+                self.year_to_date_wages,
+                self.year_to_date_contributions,
+            )
+        };
+        let reaches_target = |cents: i64| breakdown_for(cents).net_pay >= net_pay;
+
+        let cents = smallest_reaching_target(0, MAX_GROSS_PAY.cents(), reaches_target)?;
+        // rounding at a bracket edge can let a slightly lower gross pay reach the net pay too
+        let cents = (cents.saturating_sub(GROSS_UP_ROUNDING_WINDOW).max(0)..cents)
+            .find(|&lower| reaches_target(lower))
+            .unwrap_or(cents);
+        Some(breakdown_for(cents))
+    }
+}
+
+/// Target result of the hourly rate solver
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateTarget {
+    /// net pay of each paycheck is at least this amount
+    NetPaycheck(Money),
+    /// monthly net income covers the monthly expenses with at least this amount left over
+    MonthlySurplus(Money),
+}
+
+impl RateTarget {
+    /// Whether the scenario reaches the target
+    fn is_reached_by(&self, scenario: &EmploymentScenario) -> bool {
+        match *self {
+            RateTarget::NetPaycheck(net_pay) => scenario.calculate_net_paycheck() >= net_pay,
+            RateTarget::MonthlySurplus(surplus) => {
+                let (_, _, difference) = scenario.compare_monthly_expenses_to_monthly_income();
+                difference >= surplus
+            }
+        }
+    }
+}
+
+impl fmt::Display for RateTarget {
+    /// Describes the target, e.g. "a net paycheck of $1500.00"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RateTarget::NetPaycheck(net_pay) => write!(f, "a net paycheck of ${net_pay}"),
+            RateTarget::MonthlySurplus(surplus) => {
+                write!(f, "${surplus} left over each month after expenses")
+            }
+        }
+    }
+}
+
+/// Smallest value in `low..=high` for which `reaches_target` is true, assuming that once a value reaches the target every larger value does too
+fn smallest_reaching_target(
+    mut low: i64,
+    mut high: i64,
+    mut reaches_target: impl FnMut(i64) -> bool,
+) -> Option<i64> {
+    if !reaches_target(high) {
+        return None;
+    }
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if reaches_target(middle) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+    Some(low)
+}
+
+// UNIT TESTS FOR SOLVER MODULE
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::ScenarioBuilder;
+    use crate::*;
+
+    /// The deductions of the `lib.rs` example at $25/hour for 45 hours, for a $1,425.02 net paycheck
+    fn example_scenario() -> EmploymentScenario {
+        ScenarioBuilder::hourly(25, 45.0)
+            .pretax(vec![
+                PreTaxDeduction::Medical(Some(Money::new(100, 0).into())),
+                PreTaxDeduction::Dental(Some(Money::new(50, 0).into())),
+                PreTaxDeduction::Vision(Some(Money::new(25, 0).into())),
+                PreTaxDeduction::Traditional401K(Some(Money::new(200, 0).into())),
+                PreTaxDeduction::HSA(Some(Money::new(150, 0).into())),
+            ])
+            .posttax(vec![
+                PostTaxDeduction::Roth401K(Some(Money::new(100, 0).into())),
+                PostTaxDeduction::VoluntaryLife(Some(Money::new(30, 0).into())),
+            ])
+            .expenses(vec![
+                Expense::Housing(Some(Money::new(1500, 0))),
+                Expense::Energy(Some(Money::new(200, 0))),
+                Expense::Water(Some(Money::new(50, 0))),
+                Expense::Groceries(Some(Money::new(400, 0))),
+                Expense::Phone(Some(Money::new(80, 0))),
+                Expense::Internet(Some(Money::new(60, 0))),
+            ])
+            .build()
+    }
+
+    fn monthly_difference(scenario: &EmploymentScenario, hours_per_week: f32) -> Money {
+        let mut scenario = scenario.clone();
+        scenario.pay_basis = scenario.pay_basis.with_hours_per_week(hours_per_week);
+        scenario.compare_monthly_expenses_to_monthly_income().2
+    }
+
+    #[test]
+    fn test_solve_hours_per_week_is_minimum() {
+        let scenario = example_scenario();
+        for target_surplus in [Money::ZERO, Money::new(500, 0), Money::new(1500, 0)] {
+            let hours = scenario.solve_hours_per_week(target_surplus).unwrap();
+            assert!(monthly_difference(&scenario, hours) >= target_surplus);
+            assert!(monthly_difference(&scenario, hours - 0.01) < target_surplus);
+        }
+    }
+
+    #[test]
+    fn test_solve_hours_per_week_with_overtime() {
+        // 45 hours leaves $797.54 a month, so $1,500 needs more overtime hours
+        let hours = example_scenario()
+            .solve_hours_per_week(Money::new(1500, 0))
+            .unwrap();
+        assert_eq!(hours, 50.38);
+    }
+
+    #[test]
+    fn test_solve_hourly_rate_is_minimum() {
+        let scenario = example_scenario();
+        for target in [
+            RateTarget::NetPaycheck(Money::new(1425, 2)),
+            RateTarget::NetPaycheck(Money::new(3000, 0)),
+            RateTarget::MonthlySurplus(Money::ZERO),
+            RateTarget::MonthlySurplus(Money::new(797, 54)),
+        ] {
+            let rate = scenario.solve_hourly_rate(target).unwrap();
+            let mut solved = scenario.clone();
+            solved.pay_basis = PayBasis::Hourly {
+                rate,
+                hours_per_week: 45.0,
+            };
+            assert!(target.is_reached_by(&solved), "{target:?}");
+            solved.pay_basis = PayBasis::Hourly {
+                rate: rate - Money::from_cents(1),
+                hours_per_week: 45.0,
+            };
+            assert!(!target.is_reached_by(&solved), "{target:?}");
+        }
+    }
+
+    #[test]
+    fn test_solve_hourly_rate_matches_example() {
+        // the example scenario pays $25/hour for a net paycheck of $1425.02 and a $797.54 monthly difference
+        let scenario = example_scenario();
+        assert_eq!(
+            scenario.solve_hourly_rate(RateTarget::NetPaycheck(Money::new(1425, 2))),
+            Some(Money::new(25, 0))
+        );
+        assert_eq!(
+            scenario.solve_hourly_rate(RateTarget::MonthlySurplus(Money::new(797, 54))),
+            Some(Money::new(25, 0))
+        );
+    }
+
+    #[test]
+    fn test_solve_hourly_rate_unreachable() {
+        let mut scenario = example_scenario();
+        scenario.pay_basis = scenario.pay_basis.with_hours_per_week(0.0);
+        assert_eq!(
+            scenario.solve_hourly_rate(RateTarget::NetPaycheck(Money::new(100, 0))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_gross_up_matches_example() {
+        let scenario = example_scenario();
+        let net_pay = scenario.calculate_net_paycheck();
+        let breakdown = scenario.gross_up(net_pay).unwrap();
+        assert_eq!(breakdown.net_pay, net_pay);
+        assert!(breakdown.gross_pay <= Money::new(2375, 0));
+        assert!(breakdown.gross_pay > Money::new(2374, 0));
+        assert_eq!(breakdown.pretax_deductions.len(), 5);
+    }
+
+    #[test]
+    fn test_gross_up_is_exact_and_minimum() {
+        let scenario = example_scenario();
+        // a range of paychecks, including net pay around the 12% / 22% bracket edge (about $2,009)
+        for net_pay in [
+            Money::ZERO,
+            Money::new(1, 0),
+            Money::new(1234, 56),
+            Money::new(2008, 90),
+            Money::new(2008, 91),
+            Money::new(2009, 0),
+            Money::new(25_000, 0),
+        ] {
+            let breakdown = scenario.gross_up(net_pay).unwrap();
+            assert_eq!(breakdown.net_pay, net_pay, "{net_pay}");
+
+            let gross_pay = breakdown.gross_pay;
+            for cents in 1..=100 {
+                let lower = gross_pay - Money::from_cents(cents);
+                if lower < Money::ZERO {
+                    break;
+                }
+                let lower_breakdown = scenario.calculate_paycheck_breakdown_for(
+                    GrossPay {
+                        regular: lower,
+                        overtime: Money::ZERO,
+                    },
+                    Money::ZERO,
+                    ContributionTotals::default(),
+                );
+                assert!(lower_breakdown.net_pay < net_pay, "{net_pay} at {lower}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_gross_up_social_security_wage_base() {
+        let mut scenario = example_scenario();
+        let net_pay = Money::new(2000, 0);
+        let before_wage_base = scenario.gross_up(net_pay).unwrap();
+
+        // past the $184,500 wage base no Social Security is withheld, so less gross pay is needed
+        scenario.year_to_date_wages = Money::new(190_000, 0);
+        let after_wage_base = scenario.gross_up(net_pay).unwrap();
+        assert_eq!(after_wage_base.social_security, Money::ZERO);
+        assert_eq!(after_wage_base.net_pay, net_pay);
+        assert!(after_wage_base.gross_pay < before_wage_base.gross_pay);
+    }
+
+    #[test]
+    fn test_solve_hours_per_week_unreachable() {
+        assert_eq!(
+            example_scenario().solve_hours_per_week(Money::new(100_000, 0)),
+            None
+        );
+    }
+}
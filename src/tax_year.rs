@@ -0,0 +1,465 @@
+//! Module for data-driven tax year tables.
+//! A `TaxYear` holds everything that changes from one tax year to the next: tax brackets and standard deductions for each filing status, FICA rates and wage bases, and the annual 401(k), HSA and FSA contribution limits.
+//! The 2026 table is built into the library from the values in the `constants` module. Additional years can be loaded from TOML or JSON files without a code release.
+
+use crate::constants::*;
+use crate::contributions::{ContributionPlan, HsaCoverage};
+use crate::money::Money;
+use crate::utils::read_from_file;
+use anyhow::{Result, bail};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A single federal income tax bracket.
+/// Income over `over` is taxed at `rate`, plus the `base_tax` owed on all income up to `over`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TaxBracket {
+    pub over: Money,
+    pub rate: f32,
+    pub base_tax: Money,
+}
+
+/// Standard deduction and tax brackets for one filing status.
+/// Brackets must be ordered from lowest to highest, with the first bracket starting at $0.
+/// `percentage_method_adjustment` is the Publication 15-T Worksheet 1A line 1g amount subtracted from annual wages when the Form W-4 Step 2 box is not checked.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FilingStatusTable {
+    pub standard_deduction: Money,
+    pub percentage_method_adjustment: Money,
+    pub brackets: Vec<TaxBracket>,
+}
+
+/// Social Security and Medicare (FICA) rates withheld from the employee.
+/// Social Security is only withheld on wages up to `social_security_wage_base` each calendar year.
+/// Additional Medicare Tax is withheld on wages over `additional_medicare_threshold` each calendar year, regardless of filing status.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FicaRates {
+    pub social_security_rate: f32,
+    pub social_security_wage_base: Money,
+    pub medicare_rate: f32,
+    pub additional_medicare_rate: f32,
+    pub additional_medicare_threshold: Money,
+}
+
+/// Annual limits on employee contributions to retirement and health accounts.
+/// Catch-up contributions are added to the base limits by the age the employee reaches by the end of the year (see `ContributionLimits::limit`).
+/// # Fields
+/// * `elective_deferral` - 402(g) limit on traditional and Roth 401(k) contributions combined
+/// * `catch_up` - additional elective deferrals from age 50
+/// * `age_60_to_63_catch_up` - additional elective deferrals at ages 60 through 63, in place of `catch_up`
+/// * `hsa_self_only` - HSA limit for self-only coverage
+/// * `hsa_family` - HSA limit for family coverage
+/// * `hsa_catch_up` - additional HSA contributions from age 55
+/// * `health_fsa` - health FSA salary reduction limit
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ContributionLimits {
+    pub elective_deferral: Money,
+    pub catch_up: Money,
+    pub age_60_to_63_catch_up: Money,
+    pub hsa_self_only: Money,
+    pub hsa_family: Money,
+    pub hsa_catch_up: Money,
+    pub health_fsa: Money,
+}
+
+impl ContributionLimits {
+    /// Annual limit for a plan, including any catch-up contribution allowed at `age` (the age reached by the end of the year, if known)
+    /// # Example
+    /// ```
+    /// use paycheck_utils::*;
+    ///
+    /// let limits = TaxYear::default().contribution_limits;
+    /// let limit = |plan, age| limits.limit(plan, HsaCoverage::SelfOnly, age);
+    /// assert_eq!(limit(ContributionPlan::ElectiveDeferral, None), Money::new(24_500, 0));
+    /// assert_eq!(limit(ContributionPlan::ElectiveDeferral, Some(50)), Money::new(32_500, 0));
+    /// assert_eq!(limit(ContributionPlan::ElectiveDeferral, Some(61)), Money::new(35_750, 0));
+    /// assert_eq!(limit(ContributionPlan::Hsa, Some(55)), Money::new(5_400, 0));
+    /// ```
+    pub fn limit(
+        &self,
+        plan: ContributionPlan,
+        hsa_coverage: HsaCoverage,
+        age: Option<u8>,
+    ) -> Money {
+        let age = age.unwrap_or_default();
+        match plan {
+            ContributionPlan::ElectiveDeferral => {
+                self.elective_deferral
+                    + match age {
+                        60..=63 => self.age_60_to_63_catch_up,
+                        50.. => self.catch_up,
+                        _ => Money::ZERO,
+                    }
+            }
+            ContributionPlan::Hsa => {
+                let base = match hsa_coverage {
+                    HsaCoverage::SelfOnly => self.hsa_self_only,
+                    HsaCoverage::Family => self.hsa_family,
+                };
+                if age >= 55 {
+                    base + self.hsa_catch_up
+                } else {
+                    base
+                }
+            }
+            ContributionPlan::HealthFsa => self.health_fsa,
+        }
+    }
+}
+
+/// Tax table for a single tax year.
+///
+/// # Example
+/// ```
+/// use paycheck_utils::*;
+///
+/// let tax_year = TaxYear::builtin(2026).unwrap();
+/// assert_eq!(tax_year.year, 2026);
+/// assert_eq!(
+///     tax_year.filing_status(FilingStatus::MarriedFilingJointly).standard_deduction,
+///     Money::new(32_200, 0)
+/// );
+/// assert!(TaxYear::builtin(1999).is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaxYear {
+    pub year: u16,
+    pub single: FilingStatusTable,
+    pub married_filing_jointly: FilingStatusTable,
+    pub married_filing_separate: FilingStatusTable,
+    pub head_of_household: FilingStatusTable,
+    pub fica: FicaRates,
+    pub contribution_limits: ContributionLimits,
+}
+
+impl Default for TaxYear {
+    /// The most recent built-in tax year (2026)
+    fn default() -> Self {
+        TaxYear::year_2026()
+    }
+}
+
+impl TaxYear {
+    /// Years with a table built into the library
+    pub const BUILTIN_YEARS: [u16; 1] = [2026];
+
+    /// Get a built-in tax year table, or `None` if the year is not built in
+    pub fn builtin(year: u16) -> Option<Self> {
+        match year {
+            2026 => Some(TaxYear::year_2026()),
+            _ => None,
+        }
+    }
+
+    /// Load a tax year table from a `.toml` or `.json` file.
+    /// The file must contain every field of `TaxYear`. The brackets for each filing status are validated after loading.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let tax_year: TaxYear = read_from_file(path.as_ref())?;
+        tax_year.validate()?;
+        Ok(tax_year)
+    }
+
+    /// Get the standard deduction and brackets for a filing status
+    pub fn filing_status(&self, filing_status: FilingStatus) -> &FilingStatusTable {
+        match filing_status {
+            FilingStatus::Single => &self.single,
+            FilingStatus::MarriedFilingJointly => &self.married_filing_jointly,
+            FilingStatus::MarriedFilingSeparate => &self.married_filing_separate,
+            FilingStatus::HeadOfHousehold => &self.head_of_household,
+        }
+    }
+
+    /// Check that every filing status has brackets starting at $0 in ascending order
+    fn validate(&self) -> Result<()> {
+        for (name, table) in [
+            ("single", &self.single),
+            ("married_filing_jointly", &self.married_filing_jointly),
+            ("married_filing_separate", &self.married_filing_separate),
+            ("head_of_household", &self.head_of_household),
+        ] {
+            match table.brackets.first() {
+                Some(first) if first.over.is_zero() => {}
+                _ => bail!(
+                    "tax year {}: {} brackets must start with a bracket over 0",
+                    self.year,
+                    name
+                ),
+            }
+            if table
+                .brackets
+                .windows(2)
+                .any(|pair| pair[0].over >= pair[1].over)
+            {
+                bail!(
+                    "tax year {}: {} brackets must be in ascending order",
+                    self.year,
+                    name
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Built-in 2026 table using the IRS values in the `constants` module
+    fn year_2026() -> Self {
+        TaxYear {
+            year: 2026,
+            single: FilingStatusTable::from_constants(
+                SINGLE_DEDUCTION,
+                PERCENTAGE_METHOD_ADJUSTMENT,
+                [
+                    SINGLE_BRACKET_1_THRESHOLD,
+                    SINGLE_BRACKET_2_THRESHOLD,
+                    SINGLE_BRACKET_3_THRESHOLD,
+                    SINGLE_BRACKET_4_THRESHOLD,
+                    SINGLE_BRACKET_5_THRESHOLD,
+                    SINGLE_BRACKET_6_THRESHOLD,
+                ],
+                [
+                    SINGLE_BRACKET_2_BASE_TAX,
+                    SINGLE_BRACKET_3_BASE_TAX,
+                    SINGLE_BRACKET_4_BASE_TAX,
+                    SINGLE_BRACKET_5_BASE_TAX,
+                    SINGLE_BRACKET_6_BASE_TAX,
+                    SINGLE_BRACKET_7_BASE_TAX,
+                ],
+            ),
+            married_filing_jointly: FilingStatusTable::from_constants(
+                MARRIED_FILING_JOINTLY_DEDUCTION,
+                MARRIED_FILING_JOINTLY_PERCENTAGE_METHOD_ADJUSTMENT,
+                [
+                    MARRIED_JOINTLY_BRACKET_1_THRESHOLD,
+                    MARRIED_JOINTLY_BRACKET_2_THRESHOLD,
+                    MARRIED_JOINTLY_BRACKET_3_THRESHOLD,
+                    MARRIED_JOINTLY_BRACKET_4_THRESHOLD,
+                    MARRIED_JOINTLY_BRACKET_5_THRESHOLD,
+                    MARRIED_JOINTLY_BRACKET_6_THRESHOLD,
+                ],
+                [
+                    MARRIED_JOINTLY_BRACKET_2_BASE_TAX,
+                    MARRIED_JOINTLY_BRACKET_3_BASE_TAX,
+                    MARRIED_JOINTLY_BRACKET_4_BASE_TAX,
+                    MARRIED_JOINTLY_BRACKET_5_BASE_TAX,
+                    MARRIED_JOINTLY_BRACKET_6_BASE_TAX,
+                    MARRIED_JOINTLY_BRACKET_7_BASE_TAX,
+                ],
+            ),
+            married_filing_separate: FilingStatusTable::from_constants(
+                MARRIED_FILING_SEPERATE_DEDUCTION,
+                PERCENTAGE_METHOD_ADJUSTMENT,
+                [
+                    MARRIED_SEPARATELY_BRACKET_1_THRESHOLD,
+                    MARRIED_SEPARATELY_BRACKET_2_THRESHOLD,
+                    MARRIED_SEPARATELY_BRACKET_3_THRESHOLD,
+                    MARRIED_SEPARATELY_BRACKET_4_THRESHOLD,
+                    MARRIED_SEPARATELY_BRACKET_5_THRESHOLD,
+                    MARRIED_SEPARATELY_BRACKET_6_THRESHOLD,
+                ],
+                [
+                    MARRIED_SEPARATELY_BRACKET_2_BASE_TAX,
+                    MARRIED_SEPARATELY_BRACKET_3_BASE_TAX,
+                    MARRIED_SEPARATELY_BRACKET_4_BASE_TAX,
+                    MARRIED_SEPARATELY_BRACKET_5_BASE_TAX,
+                    MARRIED_SEPARATELY_BRACKET_6_BASE_TAX,
+                    MARRIED_SEPARATELY_BRACKET_7_BASE_TAX,
+                ],
+            ),
+            head_of_household: FilingStatusTable::from_constants(
+                HEAD_OF_HOUSEHOLD_DEDUCTION,
+                PERCENTAGE_METHOD_ADJUSTMENT,
+                [
+                    HEAD_OF_HOUSEHOLD_BRACKET_1_THRESHOLD,
+                    HEAD_OF_HOUSEHOLD_BRACKET_2_THRESHOLD,
+                    HEAD_OF_HOUSEHOLD_BRACKET_3_THRESHOLD,
+                    HEAD_OF_HOUSEHOLD_BRACKET_4_THRESHOLD,
+                    HEAD_OF_HOUSEHOLD_BRACKET_5_THRESHOLD,
+                    HEAD_OF_HOUSEHOLD_BRACKET_6_THRESHOLD,
+                ],
+                [
+                    HEAD_OF_HOUSEHOLD_BRACKET_2_BASE_TAX,
+                    HEAD_OF_HOUSEHOLD_BRACKET_3_BASE_TAX,
+                    HEAD_OF_HOUSEHOLD_BRACKET_4_BASE_TAX,
+                    HEAD_OF_HOUSEHOLD_BRACKET_5_BASE_TAX,
+                    HEAD_OF_HOUSEHOLD_BRACKET_6_BASE_TAX,
+                    HEAD_OF_HOUSEHOLD_BRACKET_7_BASE_TAX,
+                ],
+            ),
+            fica: FicaRates {
+                social_security_rate: SOCIAL_SECURITY_RATE,
+                social_security_wage_base: SOCIAL_SECURITY_WAGE_BASE,
+                medicare_rate: MEDICARE_RATE,
+                additional_medicare_rate: ADDITIONAL_MEDICARE_RATE,
+                additional_medicare_threshold: ADDITIONAL_MEDICARE_THRESHOLD,
+            },
+            contribution_limits: ContributionLimits {
+                elective_deferral: ELECTIVE_DEFERRAL_LIMIT,
+                catch_up: ELECTIVE_DEFERRAL_CATCH_UP,
+                age_60_to_63_catch_up: ELECTIVE_DEFERRAL_AGE_60_TO_63_CATCH_UP,
+                hsa_self_only: HSA_SELF_ONLY_LIMIT,
+                hsa_family: HSA_FAMILY_LIMIT,
+                hsa_catch_up: HSA_CATCH_UP,
+                health_fsa: HEALTH_FSA_LIMIT,
+            },
+        }
+    }
+}
+
+impl FilingStatusTable {
+    /// Build a seven bracket table from the six upper limit thresholds and six base tax amounts defined in the `constants` module
+    fn from_constants(
+        standard_deduction: Money,
+        percentage_method_adjustment: Money,
+        thresholds: [Money; 6],
+        base_taxes: [Money; 6],
+    ) -> Self {
+        let rates = [
+            TAX_BRACKET_1_RATE,
+            TAX_BRACKET_2_RATE,
+            TAX_BRACKET_3_RATE,
+            TAX_BRACKET_4_RATE,
+            TAX_BRACKET_5_RATE,
+            TAX_BRACKET_6_RATE,
+            TAX_BRACKET_7_RATE,
+        ];
+        let mut brackets = vec![TaxBracket {
+            over: Money::ZERO,
+            rate: rates[0],
+            base_tax: Money::ZERO,
+        }];
+        for bracket in 0..thresholds.len() {
+            brackets.push(TaxBracket {
+                over: thresholds[bracket],
+                rate: rates[bracket + 1],
+                base_tax: base_taxes[bracket],
+            });
+        }
+        FilingStatusTable {
+            standard_deduction,
+            percentage_method_adjustment,
+            brackets,
+        }
+    }
+
+    /// Apply the brackets to an annual taxable income amount.
+    /// Taxable income at or below $0 owes no tax.
+    pub fn apply_tax_brackets(&self, taxable_income: Money) -> Money {
+        // walk the brackets from the top down and tax the amount over the first threshold exceeded
+        self.brackets
+            .iter()
+            .rev()
+            .find(|bracket| taxable_income > bracket.over)
+            .map_or(Money::ZERO, |bracket| {
+                (taxable_income - bracket.over).multiply(bracket.rate) + bracket.base_tax
+            })
+    }
+}
+
+/// Serde `with` module for a `TaxYear` field that is written as just the year number when it is a built-in table, and as the full table otherwise.
+/// Reading accepts either a built-in year number or a full table (which is validated).
+/// # Example
+/// ```
+/// use paycheck_utils::TaxYear;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Settings {
+///     #[serde(with = "paycheck_utils::tax_year::year_or_table")]
+///     tax_year: TaxYear,
+/// }
+///
+/// let settings: Settings = toml::from_str("tax_year = 2026").unwrap();
+/// assert_eq!(settings.tax_year, TaxYear::default());
+/// assert_eq!(toml::to_string(&settings).unwrap().trim(), "tax_year = 2026");
+/// ```
+pub mod year_or_table {
+    use super::TaxYear;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(untagged)]
+    enum YearOrTable {
+        Year(u16),
+        Table(Box<TaxYear>),
+    }
+
+    pub fn serialize<S: Serializer>(tax_year: &TaxYear, serializer: S) -> Result<S::Ok, S::Error> {
+        match TaxYear::builtin(tax_year.year) {
+            Some(builtin) if builtin == *tax_year => serializer.serialize_u16(tax_year.year),
+            _ => tax_year.serialize(serializer),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TaxYear, D::Error> {
+        match YearOrTable::deserialize(deserializer)? {
+            YearOrTable::Year(year) => TaxYear::builtin(year).ok_or_else(|| {
+                D::Error::custom(format!(
+                    "tax year {year} is not built in (built-in years: {:?})",
+                    TaxYear::BUILTIN_YEARS
+                ))
+            }),
+            YearOrTable::Table(tax_year) => {
+                tax_year.validate().map_err(D::Error::custom)?;
+                Ok(*tax_year)
+            }
+        }
+    }
+}
+
+// UNIT TESTS FOR TAX YEAR MODULE
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_2026_brackets() {
+        let tax_year = TaxYear::builtin(2026).unwrap();
+        for filing_status in [
+            FilingStatus::Single,
+            FilingStatus::MarriedFilingJointly,
+            FilingStatus::MarriedFilingSeparate,
+            FilingStatus::HeadOfHousehold,
+        ] {
+            assert_eq!(tax_year.filing_status(filing_status).brackets.len(), 7);
+        }
+        assert!(tax_year.validate().is_ok());
+    }
+
+    #[test]
+    fn test_load_tax_year_from_toml_and_json() {
+        let tax_year = TaxYear::default();
+        let dir = std::env::temp_dir();
+        let toml_path = dir.join("paycheck_utils_tax_year_test.toml");
+        let json_path = dir.join("paycheck_utils_tax_year_test.json");
+        std::fs::write(&toml_path, toml::to_string(&tax_year).unwrap()).unwrap();
+        std::fs::write(&json_path, serde_json::to_string(&tax_year).unwrap()).unwrap();
+
+        assert_eq!(TaxYear::from_file(&toml_path).unwrap(), tax_year);
+        assert_eq!(TaxYear::from_file(&json_path).unwrap(), tax_year);
+
+        std::fs::remove_file(toml_path).unwrap();
+        std::fs::remove_file(json_path).unwrap();
+    }
+
+    #[test]
+    fn test_tax_year_template_matches_builtin() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tax_years/2026.toml");
+        assert_eq!(TaxYear::from_file(path).unwrap(), TaxYear::default());
+    }
+
+    #[test]
+    fn test_load_tax_year_rejects_unordered_brackets() {
+        let mut tax_year = TaxYear {
+            year: 2027,
+            ..TaxYear::default()
+        };
+        tax_year.single.brackets.swap(1, 2);
+        let path = std::env::temp_dir().join("paycheck_utils_tax_year_unordered.json");
+        std::fs::write(&path, serde_json::to_string(&tax_year).unwrap()).unwrap();
+
+        assert!(TaxYear::from_file(&path).is_err());
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,45 @@
+//! Module for the scenario builder shared by the unit tests of the other modules.
+
+use crate::*;
+
+/// Builds an `EmploymentScenario` for unit tests: a single filer paid hourly and bi-weekly, with no deductions or expenses until they are added
+/// e.g. `ScenarioBuilder::hourly(50, 40.0).pretax(vec![PreTaxDeduction::HSA(Some(Money::new(400, 0).into()))]).build()`
+pub(crate) struct ScenarioBuilder {
+    scenario: EmploymentScenario,
+}
+
+impl ScenarioBuilder {
+    /// Paid `rate` whole dollars per hour for `hours_per_week` hours
+    pub(crate) fn hourly(rate: i64, hours_per_week: f32) -> Self {
+        ScenarioBuilder {
+            scenario: EmploymentScenario::new(
+                Money::new(rate, 0),
+                hours_per_week,
+                FilingStatus::Single,
+                PreTaxDeductions::default(),
+                PostTaxDeductions::default(),
+                Expenses::default(),
+            ),
+        }
+    }
+
+    pub(crate) fn pretax(mut self, deductions: Vec<PreTaxDeduction>) -> Self {
+        self.scenario.pretax_deductions = PreTaxDeductions::new(deductions);
+        self
+    }
+
+    pub(crate) fn posttax(mut self, deductions: Vec<PostTaxDeduction>) -> Self {
+        self.scenario.posttax_deductions = PostTaxDeductions::new(deductions);
+        self
+    }
+
+    /// Monthly expenses
+    pub(crate) fn expenses(mut self, expenses: Vec<Expense>) -> Self {
+        self.scenario.expenses = Expenses::new(expenses);
+        self
+    }
+
+    pub(crate) fn build(self) -> EmploymentScenario {
+        self.scenario
+    }
+}
@@ -20,7 +20,13 @@ pub fn round_2_decimals(value: f32) -> f32 {
     (value * 100.0).round() / 100.0
 }
 
+use crate::money::Money;
+use anyhow::{Context, Result, bail};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 use std::any::{Any, TypeId};
+use std::fmt::Display;
+use std::path::Path;
 use std::str::FromStr;
 
 pub fn check_converted_value<T: Any + FromStr>(
@@ -33,6 +39,180 @@ pub fn check_converted_value<T: Any + FromStr>(
     }
 }
 
+/// Splits a `KIND=AMOUNT` argument (e.g. `medical=150` or `car-payment=$350.00`) into a normalized kind and an amount (a `Money` amount, or any other type parsed from text such as a `DeductionAmount`)
+/// # Arguments
+/// * `value` - the `KIND=AMOUNT` text
+/// # Returns
+/// * `Ok((kind, amount))` - the kind in lowercase with spaces, `-` and `_` removed, and the parsed amount
+/// * `Err(String)` - a message describing why the text could not be parsed
+/// # Example
+/// ```
+/// use paycheck_utils::{Money, utils::parse_kind_amount};
+///
+/// let (kind, amount) = parse_kind_amount::<Money>("Voluntary-Life=30").unwrap();
+/// assert_eq!(kind, "voluntarylife");
+/// assert_eq!(amount, Money::new(30, 0));
+/// ```
+pub fn parse_kind_amount<T>(value: &str) -> Result<(String, T), String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let (kind, amount) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected KIND=AMOUNT, got '{value}'"))?;
+    let kind: String = kind
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .collect::<String>()
+        .to_lowercase();
+    let amount = amount
+        .parse::<T>()
+        .map_err(|error| format!("{error} in '{value}'"))?;
+    Ok((kind, amount))
+}
+
+/// The name, option and amount of a `custom:NAME[:OPTION]=AMOUNT` argument
+pub type CustomAmount<T = Money> = (String, Option<String>, T);
+
+/// Splits a `custom:NAME=AMOUNT` or `custom:NAME:OPTION=AMOUNT` argument for a user-named expense or deduction
+/// # Arguments
+/// * `value` - the argument text
+/// * `with_option` - whether the text after the last `:` of the name is an option (e.g. an expense group); otherwise it is part of the name
+/// # Returns
+/// * `None` - when the text does not start with `custom:`, so it should be parsed as `KIND=AMOUNT`
+/// * `Some(Ok((name, option, amount)))` - the trimmed name, the option (if any) and the parsed amount
+/// * `Some(Err(String))` - a message describing why the text could not be parsed
+/// # Example
+/// ```
+/// use paycheck_utils::{Money, utils::parse_custom_amount};
+///
+/// let (name, option, amount) = parse_custom_amount::<Money>("custom:Childcare:Family=800", true).unwrap().unwrap();
+/// assert_eq!((name.as_str(), option.as_deref(), amount), ("Childcare", Some("Family"), Money::new(800, 0)));
+/// assert!(parse_custom_amount::<Money>("housing=1500", true).is_none());
+/// ```
+pub fn parse_custom_amount<T>(
+    value: &str,
+    with_option: bool,
+) -> Option<Result<CustomAmount<T>, String>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let (prefix, custom) = value.split_once(':')?;
+    if !prefix.trim().eq_ignore_ascii_case("custom") {
+        return None;
+    }
+    let Some((name, amount)) = custom.rsplit_once('=') else {
+        return Some(Err(format!("expected custom:NAME=AMOUNT, got '{value}'")));
+    };
+    let (name, option) = match name.rsplit_once(':') {
+        Some((name, option)) if with_option => (name.trim(), Some(option.trim().to_string())),
+        _ => (name.trim(), None),
+    };
+    if name.is_empty() {
+        return Some(Err(format!("missing custom name in '{value}'")));
+    }
+    Some(
+        amount
+            .parse::<T>()
+            .map(|amount| {
+                (
+                    name.to_string(),
+                    option.filter(|option| !option.is_empty()),
+                    amount,
+                )
+            })
+            .map_err(|error| format!("{error} in '{value}'")),
+    )
+}
+
+/// Splits command-line flags given in one argument into words the way a shell does, so a quoted value can contain spaces
+/// # Arguments
+/// * `value` - the flags, with words separated by whitespace, `'single'` or `"double"` quoted parts and `\\` escaping the next character
+/// # Returns
+/// * `Ok(words)` - each word with its quotes and escapes removed
+/// * `Err(String)` - a message when a quote is not closed or the text ends with a `\\`
+/// # Example
+/// ```
+/// use paycheck_utils::utils::split_shell_words;
+///
+/// let words = split_shell_words("--rate 30 --expense 'custom:Student Loan=300'").unwrap();
+/// assert_eq!(words, vec!["--rate", "30", "--expense", "custom:Student Loan=300"]);
+/// ```
+pub fn split_shell_words(value: &str) -> Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => words.extend(word.take()),
+            '\'' => {
+                let word = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => word.push(c),
+                        None => return Err(format!("unclosed ' quote in \"{value}\"")),
+                    }
+                }
+            }
+            '"' => {
+                let word = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c) => word.push(c),
+                            None => return Err(format!("unclosed \" quote in '{value}'")),
+                        },
+                        Some(c) => word.push(c),
+                        None => return Err(format!("unclosed \" quote in '{value}'")),
+                    }
+                }
+            }
+            '\\' => match chars.next() {
+                Some(c) => word.get_or_insert_with(String::new).push(c),
+                None => return Err(format!("trailing \\ in \"{value}\"")),
+            },
+            c => word.get_or_insert_with(String::new).push(c),
+        }
+    }
+    words.extend(word);
+    Ok(words)
+}
+
+/// Reads and deserializes a `.toml` or `.json` file, choosing the format from the file extension
+pub fn read_from_file<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("could not read {}", path.display()))?;
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("toml") => toml::from_str(&contents)
+            .with_context(|| format!("could not parse {} as TOML", path.display())),
+        Some("json") => serde_json::from_str(&contents)
+            .with_context(|| format!("could not parse {} as JSON", path.display())),
+        _ => bail!(
+            "unsupported file type for {} (expected .toml or .json)",
+            path.display()
+        ),
+    }
+}
+
+/// Serializes and writes a `.toml` or `.json` file, choosing the format from the file extension
+pub fn write_to_file<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    let contents = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("toml") => toml::to_string_pretty(value)
+            .with_context(|| format!("could not write {} as TOML", path.display()))?,
+        Some("json") => serde_json::to_string_pretty(value)
+            .with_context(|| format!("could not write {} as JSON", path.display()))?,
+        _ => bail!(
+            "unsupported file type for {} (expected .toml or .json)",
+            path.display()
+        ),
+    };
+    std::fs::write(path, contents).with_context(|| format!("could not write {}", path.display()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -52,4 +232,28 @@ mod tests {
         assert!(check_converted_value(&result_ok, TypeId::of::<f32>()));
         assert!(!check_converted_value(&result_err, TypeId::of::<f32>()));
     }
+
+    #[test]
+    fn test_split_shell_words() {
+        assert_eq!(
+            split_shell_words("  --rate 30\t--hours 40 ").unwrap(),
+            vec!["--rate", "30", "--hours", "40"]
+        );
+        assert_eq!(
+            split_shell_words(r#"--expense "custom:Student Loan=300" --pretax custom:My\ Plan=5"#)
+                .unwrap(),
+            vec![
+                "--expense",
+                "custom:Student Loan=300",
+                "--pretax",
+                "custom:My Plan=5"
+            ]
+        );
+        assert_eq!(
+            split_shell_words(r#"a'b c'"d \" e" ''"#).unwrap(),
+            vec!["ab cd \" e", ""]
+        );
+        assert!(split_shell_words("--expense 'custom:Student Loan=300").is_err());
+        assert!(split_shell_words("--rate 30 \\").is_err());
+    }
 }
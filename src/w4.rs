@@ -0,0 +1,34 @@
+//! Module for Form W-4 (2020 and later) employee withholding inputs.
+//! The filing status from Step 1(c) is the `filing_status` of the employment scenario; the remaining steps are held in the `W4` struct and used by the Publication 15-T percentage method in the `withholdings` module.
+
+use crate::money::Money;
+use serde::{Deserialize, Serialize};
+
+/// Form W-4 (2020 and later) Steps 2 through 4.
+/// A default `W4` is a form with only Step 1 filled out, which withholds the same as subtracting the standard deduction from annualized wages.
+/// # Fields
+/// * `multiple_jobs` - Step 2(c): box checked for multiple jobs or a spouse who also works
+/// * `dependents_credit` - Step 3: total annual amount claimed for dependents and other credits
+/// * `other_income` - Step 4(a): annual other income not from jobs
+/// * `deductions` - Step 4(b): annual deductions other than the standard deduction
+/// * `extra_withholding` - Step 4(c): extra withholding each pay period
+/// # Example
+/// ```
+/// use paycheck_utils::{Money, W4};
+///
+/// let w4 = W4 {
+///     dependents_credit: Money::new(2_000, 0), // one qualifying child
+///     extra_withholding: Money::new(25, 0),
+///     ..W4::default()
+/// };
+/// assert!(!w4.multiple_jobs);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct W4 {
+    pub multiple_jobs: bool,
+    pub dependents_credit: Money,
+    pub other_income: Money,
+    pub deductions: Money,
+    pub extra_withholding: Money,
+}
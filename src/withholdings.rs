@@ -1,83 +1,218 @@
 //! Module for estimating various paycheck withholdings based on gross pay and filing status.
-//! Uses IRS guidelines for the year 2026, focusing on single filer status.
-//! Future implementation plans exist for other filing statuses.
+//! Federal withholding uses the brackets and standard deductions of a `TaxYear` table for all four filing statuses.
 
 use crate::constants::*;
-use crate::utils::round_2_decimals;
+use crate::money::Money;
+use crate::tax_year::TaxYear;
+use crate::w4::W4;
 
 /// Estimate federal tax withholding for a single paycheck based on gross paycheck and filing status
 /// # Arguments
 /// * `gross_paycheck` - The gross amount of the paycheck
 /// * `filing_status` - The filing status of the individual (e.g., Single)
+/// * `tax_year` - The tax year table providing the standard deduction and brackets
+/// * `pay_frequency` - How often paychecks are issued, used to annualize the paycheck
 /// # Returns
 /// * Estimated federal tax withholding for the paycheck
 /// # Example
 /// ```
-/// use paycheck_utils::FilingStatus;
+/// # #![allow(deprecated)]
+/// use paycheck_utils::{FilingStatus, Money, PayFrequency, TaxYear};
 /// use paycheck_utils::withholdings::estimate_paycheck_federal_withholdings;
 ///
-/// let gross_paycheck = 2000.0;
+/// let gross_paycheck = Money::new(2000, 0);
 /// let filing_status = FilingStatus::Single;
-/// let federal_withholding = estimate_paycheck_federal_withholdings(gross_paycheck, filing_status);
+/// let tax_year = TaxYear::builtin(2026).unwrap();
+/// let federal_withholding = estimate_paycheck_federal_withholdings(gross_paycheck, filing_status, &tax_year, PayFrequency::BiWeekly);
 /// println!("Estimated Federal Withholding: ${}", federal_withholding);
 /// ```
 /// # Notes
-/// This function annualizes the gross paycheck, applies the standard deduction based on filing status,
-/// and calculates the estimated federal tax using the 2026 tax brackets. The result is then
-/// converted back to a per-paycheck amount.
+/// This function annualizes the gross paycheck by the pay periods in a year, applies the standard deduction based on filing status,
+/// and calculates the estimated federal tax using the tax year's brackets for that filing status. The result is then
+/// converted back to a per-paycheck amount, rounded half up to the cent.
+/// It gives the same result as `estimate_percentage_method_withholding` with a default `W4`, except for married filing separately, which the percentage method withholds at single rates.
+/// Paycheck calculations use `estimate_percentage_method_withholding`, which also applies the employee's W-4.
+#[deprecated(
+    note = "use `estimate_percentage_method_withholding`, which follows IRS Publication 15-T and applies the W-4"
+)]
 pub fn estimate_paycheck_federal_withholdings(
-    gross_paycheck: f32,
+    gross_paycheck: Money,
     filing_status: FilingStatus,
-) -> f32 {
-    let gross_annualized_paycheck = gross_paycheck * PAY_PERIODS_PER_YEAR;
-
-    let standard_deduction = match filing_status {
-        FilingStatus::Single => SINGLE_DEDUCTION,
-        FilingStatus::HeadOfHousehold => HEAD_OF_HOUSEHOLD_DEDUCTION, // for future implementation
-        FilingStatus::MarriedFilingJointly => MARRIED_FILING_JOINTLY_DEDUCTION, // for future implementation
-        FilingStatus::MarriedFilingSeparate => MARRIED_FILING_SEPERATE_DEDUCTION, // for future implementation
-    };
+    tax_year: &TaxYear,
+    pay_frequency: PayFrequency,
+) -> Money {
+    let periods_per_year = pay_frequency.periods_per_year();
+    let gross_annualized_paycheck = gross_paycheck.multiply(periods_per_year);
+
+    let table = tax_year.filing_status(filing_status);
 
-    let adjusted_annualized_paycheck = gross_annualized_paycheck - standard_deduction;
+    let adjusted_annualized_paycheck = gross_annualized_paycheck - table.standard_deduction;
 
-    let estimated_annual_withholdings = apply_tax_brackets(adjusted_annualized_paycheck);
+    let estimated_annual_withholdings = table.apply_tax_brackets(adjusted_annualized_paycheck);
 
-    round_2_decimals(estimated_annual_withholdings / PAY_PERIODS_PER_YEAR) // estimated per-paycheck federal withholding
+    estimated_annual_withholdings.divide(periods_per_year) // estimated per-paycheck federal withholding
 }
 
-/// Apply 2026 federal tax brackets for single filers to the adjusted annualized paycheck
-fn apply_tax_brackets(adjusted_annualized_paycheck: f32) -> f32 {
-    if adjusted_annualized_paycheck > SINGLE_BRACKET_6_THRESHOLD {
-        ((adjusted_annualized_paycheck - SINGLE_BRACKET_6_THRESHOLD) * TAX_BRACKET_7_RATE)
-            + SINGLE_BRACKET_7_BASE_TAX
-    } else if adjusted_annualized_paycheck > SINGLE_BRACKET_5_THRESHOLD {
-        ((adjusted_annualized_paycheck - SINGLE_BRACKET_5_THRESHOLD) * TAX_BRACKET_6_RATE)
-            + SINGLE_BRACKET_6_BASE_TAX
-    } else if adjusted_annualized_paycheck > SINGLE_BRACKET_4_THRESHOLD {
-        ((adjusted_annualized_paycheck - SINGLE_BRACKET_4_THRESHOLD) * TAX_BRACKET_5_RATE)
-            + SINGLE_BRACKET_5_BASE_TAX
-    } else if adjusted_annualized_paycheck > SINGLE_BRACKET_3_THRESHOLD {
-        ((adjusted_annualized_paycheck - SINGLE_BRACKET_3_THRESHOLD) * TAX_BRACKET_4_RATE)
-            + SINGLE_BRACKET_4_BASE_TAX
-    } else if adjusted_annualized_paycheck > SINGLE_BRACKET_2_THRESHOLD {
-        ((adjusted_annualized_paycheck - SINGLE_BRACKET_2_THRESHOLD) * TAX_BRACKET_3_RATE)
-            + SINGLE_BRACKET_3_BASE_TAX
-    } else if adjusted_annualized_paycheck > SINGLE_BRACKET_1_THRESHOLD {
-        ((adjusted_annualized_paycheck - SINGLE_BRACKET_1_THRESHOLD) * TAX_BRACKET_2_RATE)
-            + SINGLE_BRACKET_2_BASE_TAX
+/// Estimate federal tax withholding for a single paycheck with the IRS Publication 15-T percentage method for automated payroll systems (Worksheet 1A) and a Form W-4 from 2020 or later
+/// # Arguments
+/// * `gross_paycheck` - The wages in this paycheck subject to federal income tax withholding
+/// * `filing_status` - The filing status from Step 1(c) of the W-4
+/// * `w4` - Steps 2 through 4 of the W-4
+/// * `tax_year` - The tax year table providing the standard deduction, brackets and percentage method adjustment
+/// * `pay_frequency` - How often paychecks are issued, used to annualize the paycheck
+/// # Returns
+/// * Estimated federal tax withholding for the paycheck
+/// # Example
+/// ```
+/// use paycheck_utils::{FilingStatus, Money, PayFrequency, TaxYear, W4};
+/// use paycheck_utils::withholdings::estimate_percentage_method_withholding;
+///
+/// let tax_year = TaxYear::builtin(2026).unwrap();
+/// let gross_paycheck = Money::new(2000, 0);
+///
+/// let step_1_only = estimate_percentage_method_withholding(gross_paycheck, FilingStatus::Single, &W4::default(), &tax_year, PayFrequency::BiWeekly);
+/// assert_eq!(step_1_only, Money::new(156, 15));
+///
+/// let with_dependents = W4 { dependents_credit: Money::new(2_000, 0), ..W4::default() };
+/// let withholding = estimate_percentage_method_withholding(gross_paycheck, FilingStatus::Single, &with_dependents, &tax_year, PayFrequency::BiWeekly);
+/// assert_eq!(withholding, Money::new(79, 23)); // 156.15 - (2,000 / 26 = 76.92)
+/// ```
+/// # Notes
+/// The worksheet steps are:
+///    1. Annualize the paycheck by the pay periods in a year, add Step 4(a) other income, and subtract Step 4(b) deductions. When the Step 2 box is not checked, also subtract the line 1g adjustment ($12,900 married filing jointly, $8,600 otherwise).
+///    2. Find the tentative annual withholding in the standard rate schedule (brackets shifted by the standard deduction less the line 1g adjustment), or in the Step 2 checkbox rate schedule (half the standard deduction and half-width brackets) when the Step 2 box is checked, and divide it by the pay periods.
+///    3. Subtract the Step 3 credits divided by the pay periods (not below zero).
+///    4. Add the Step 4(c) extra withholding.
+///
+/// Married filing separately uses the single rate schedules, since the W-4 combines "Single or Married filing separately" in Step 1(c).
+pub fn estimate_percentage_method_withholding(
+    gross_paycheck: Money,
+    filing_status: FilingStatus,
+    w4: &W4,
+    tax_year: &TaxYear,
+    pay_frequency: PayFrequency,
+) -> Money {
+    let periods_per_year = pay_frequency.periods_per_year();
+    let schedule_status = match filing_status {
+        FilingStatus::MarriedFilingSeparate => FilingStatus::Single,
+        other => other,
+    };
+    let table = tax_year.filing_status(schedule_status);
+
+    // step 1: adjusted annual wage amount
+    let annual_wages = gross_paycheck.multiply(periods_per_year) + w4.other_income;
+    let adjustment = if w4.multiple_jobs {
+        Money::ZERO
     } else {
-        adjusted_annualized_paycheck * TAX_BRACKET_1_RATE
-    }
+        table.percentage_method_adjustment
+    };
+    let adjusted_annual_wages = (annual_wages - (w4.deductions + adjustment)).max(Money::ZERO);
+
+    // step 2: tentative withholding from the rate schedule
+    let annual_tax = if w4.multiple_jobs {
+        // the checkbox schedule halves the standard deduction and the bracket widths
+        let doubled = adjusted_annual_wages.multiply(2.0) - table.standard_deduction;
+        table.apply_tax_brackets(doubled).divide(2.0)
+    } else {
+        table.apply_tax_brackets(
+            adjusted_annual_wages - (table.standard_deduction - table.percentage_method_adjustment),
+        )
+    };
+    let tentative_withholding = annual_tax.divide(periods_per_year);
+
+    // step 3: tax credits
+    let credits = w4.dependents_credit.divide(periods_per_year);
+    let after_credits = (tentative_withholding - credits).max(Money::ZERO);
+
+    // step 4: extra withholding
+    after_credits + w4.extra_withholding
 }
 
-/// Estimate Social Security tax withholding for a single paycheck
-pub fn estimate_social_security_withholding(gross_paycheck: f32) -> f32 {
-    gross_paycheck * SOCIAL_SECURITY_RATE
+/// Estimate Social Security tax withholding for a single paycheck using the tax year's Social Security rate and wage base
+/// # Arguments
+/// * `gross_paycheck` - The wages in this paycheck subject to Social Security
+/// * `year_to_date_wages` - Wages subject to Social Security already paid this calendar year, before this paycheck
+/// * `tax_year` - The tax year table providing the Social Security rate and wage base
+/// # Returns
+/// * Estimated Social Security withholding for the paycheck. Only the part of the paycheck below the wage base is taxed, so the withholding drops to zero once year-to-date wages reach the wage base.
+/// # Example
+/// ```
+/// use paycheck_utils::{Money, TaxYear};
+/// use paycheck_utils::withholdings::estimate_social_security_withholding;
+///
+/// let tax_year = TaxYear::builtin(2026).unwrap(); // $184,500 wage base
+/// let gross_paycheck = Money::new(10_000, 0);
+///
+/// // early in the year the whole paycheck is taxed
+/// let early = estimate_social_security_withholding(gross_paycheck, Money::ZERO, &tax_year);
+/// assert_eq!(early, Money::new(620, 0));
+///
+/// // only $4,500 of this paycheck is under the wage base
+/// let crossing = estimate_social_security_withholding(gross_paycheck, Money::new(180_000, 0), &tax_year);
+/// assert_eq!(crossing, Money::new(279, 0));
+///
+/// // the wage base has been reached
+/// let capped = estimate_social_security_withholding(gross_paycheck, Money::new(190_000, 0), &tax_year);
+/// assert_eq!(capped, Money::ZERO);
+/// ```
+pub fn estimate_social_security_withholding(
+    gross_paycheck: Money,
+    year_to_date_wages: Money,
+    tax_year: &TaxYear,
+) -> Money {
+    let remaining_wage_base =
+        (tax_year.fica.social_security_wage_base - year_to_date_wages).max(Money::ZERO);
+    gross_paycheck
+        .min(remaining_wage_base)
+        .multiply(tax_year.fica.social_security_rate)
+}
+
+/// Medicare withholding for a single paycheck, split into the base Medicare tax and the Additional Medicare Tax
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MedicareWithholding {
+    pub base: Money,
+    pub additional: Money,
 }
 
-/// Estimate Medicare tax withholding for a single paycheck
-pub fn estimate_medicare_withholding(gross_paycheck: f32) -> f32 {
-    gross_paycheck * MEDICARE_RATE
+impl MedicareWithholding {
+    /// Total Medicare withholding (base + additional)
+    pub fn total(&self) -> Money {
+        self.base + self.additional
+    }
+}
+
+/// Estimate Medicare tax withholding for a single paycheck using the tax year's Medicare rates
+/// # Arguments
+/// * `gross_paycheck` - The wages in this paycheck subject to Medicare
+/// * `year_to_date_wages` - Wages subject to Medicare already paid this calendar year, before this paycheck
+/// * `tax_year` - The tax year table providing the Medicare and Additional Medicare rates and threshold
+/// # Returns
+/// * `MedicareWithholding` with the base Medicare tax on the whole paycheck and the Additional Medicare Tax on the part of the paycheck over the threshold
+/// # Example
+/// ```
+/// use paycheck_utils::{Money, TaxYear};
+/// use paycheck_utils::withholdings::estimate_medicare_withholding;
+///
+/// let tax_year = TaxYear::builtin(2026).unwrap(); // 0.9% additional over $200,000
+/// let gross_paycheck = Money::new(10_000, 0);
+///
+/// let medicare = estimate_medicare_withholding(gross_paycheck, Money::new(196_000, 0), &tax_year);
+/// assert_eq!(medicare.base, Money::new(145, 0)); // 1.45% of $10,000
+/// assert_eq!(medicare.additional, Money::new(54, 0)); // 0.9% of the $6,000 over $200,000
+/// assert_eq!(medicare.total(), Money::new(199, 0));
+/// ```
+pub fn estimate_medicare_withholding(
+    gross_paycheck: Money,
+    year_to_date_wages: Money,
+    tax_year: &TaxYear,
+) -> MedicareWithholding {
+    let below_threshold =
+        (tax_year.fica.additional_medicare_threshold - year_to_date_wages).max(Money::ZERO);
+    let over_threshold = (gross_paycheck - below_threshold).max(Money::ZERO);
+    MedicareWithholding {
+        base: gross_paycheck.multiply(tax_year.fica.medicare_rate),
+        additional: over_threshold.multiply(tax_year.fica.additional_medicare_rate),
+    }
 }
 
 // UNIT TESTS FOR WITHHOLDINGS MODULE
@@ -88,43 +223,313 @@ mod tests {
 
     // TESTS FOR ESTIMATE_PAYCHECK_FEDERAL_WITHHOLDINGS FUNCTION
     #[test]
+    #[allow(deprecated)]
     fn test_federal_withholding() {
-        let gross_paycheck = 2000.0;
+        let gross_paycheck = Money::new(2000, 0);
         let filing_status = FilingStatus::Single;
-        let result = estimate_paycheck_federal_withholdings(gross_paycheck, filing_status);
-        let expected = 156.15; // Expected value based on 2026 tax brackets
-        assert!(
-            (result - expected).abs() < 0.01,
-            "Expected {}, got {}",
-            expected,
-            result
+        let result = estimate_paycheck_federal_withholdings(
+            gross_paycheck,
+            filing_status,
+            &TaxYear::default(),
+            PayFrequency::BiWeekly,
+        );
+        let expected = Money::new(156, 15); // Expected value based on 2026 tax brackets
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_federal_withholding_annualizes_by_pay_frequency() {
+        // $52,000 a year paid weekly or monthly: 52,000 - 16,100 = 35,900 -> 1,240 + 23,500 * 12% = 4,060
+        let weekly = estimate_paycheck_federal_withholdings(
+            Money::new(1000, 0),
+            FilingStatus::Single,
+            &TaxYear::default(),
+            PayFrequency::Weekly,
+        );
+        assert_eq!(weekly, Money::new(78, 8)); // 4,060 / 52
+        let monthly = estimate_percentage_method_withholding(
+            Money::new(52_000, 0).divide(12.0),
+            FilingStatus::Single,
+            &W4::default(),
+            &TaxYear::default(),
+            PayFrequency::Monthly,
+        );
+        assert_eq!(monthly, Money::new(338, 33)); // 4,060 / 12
+    }
+
+    // TESTS FOR 2026 TAX BRACKETS BY FILING STATUS
+    /// Checks the tax at each bracket boundary (where the base tax of the next bracket begins)
+    /// and $100 past each boundary (where the next bracket's marginal rate applies).
+    fn assert_bracket_boundaries(filing_status: FilingStatus, expected: [(f64, f64, f64); 6]) {
+        let table = TaxYear::default().filing_status(filing_status).clone();
+        for (threshold, base_tax, marginal_rate) in expected {
+            let threshold = Money::from_dollars(threshold);
+            let base_tax = Money::from_dollars(base_tax);
+            assert_eq!(
+                table.apply_tax_brackets(threshold),
+                base_tax,
+                "{:?} at {}",
+                filing_status,
+                threshold
+            );
+            let past_boundary = threshold + Money::new(100, 0);
+            assert_eq!(
+                table.apply_tax_brackets(past_boundary),
+                base_tax + Money::from_dollars(100.0 * marginal_rate),
+                "{:?} at {}",
+                filing_status,
+                past_boundary
+            );
+        }
+    }
+
+    #[test]
+    fn test_tax_brackets_single() {
+        assert_bracket_boundaries(
+            FilingStatus::Single,
+            [
+                (12400.0, 1240.0, 0.12),
+                (50400.0, 5800.0, 0.22),
+                (105700.0, 17966.0, 0.24),
+                (201775.0, 41024.0, 0.32),
+                (256225.0, 58448.0, 0.35),
+                (640600.0, 192979.25, 0.37),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_tax_brackets_married_filing_jointly() {
+        assert_bracket_boundaries(
+            FilingStatus::MarriedFilingJointly,
+            [
+                (24800.0, 2480.0, 0.12),
+                (100800.0, 11600.0, 0.22),
+                (211400.0, 35932.0, 0.24),
+                (403550.0, 82048.0, 0.32),
+                (512450.0, 116896.0, 0.35),
+                (768700.0, 206583.5, 0.37),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_tax_brackets_married_filing_separate() {
+        assert_bracket_boundaries(
+            FilingStatus::MarriedFilingSeparate,
+            [
+                (12400.0, 1240.0, 0.12),
+                (50400.0, 5800.0, 0.22),
+                (105700.0, 17966.0, 0.24),
+                (201775.0, 41024.0, 0.32),
+                (256225.0, 58448.0, 0.35),
+                (384350.0, 103291.75, 0.37),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_tax_brackets_head_of_household() {
+        assert_bracket_boundaries(
+            FilingStatus::HeadOfHousehold,
+            [
+                (17700.0, 1770.0, 0.12),
+                (67450.0, 7740.0, 0.22),
+                (105700.0, 16155.0, 0.24),
+                (201750.0, 39207.0, 0.32),
+                (256200.0, 56631.0, 0.35),
+                (640600.0, 191171.0, 0.37),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_tax_brackets_below_zero() {
+        let table = TaxYear::default()
+            .filing_status(FilingStatus::Single)
+            .clone();
+        assert_eq!(table.apply_tax_brackets(Money::new(-500, 0)), Money::ZERO);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_federal_withholding_married_filing_jointly() {
+        // annualized: 2000 * 26 = 52,000 - 32,200 = 19,800 taxed at 10% = 1,980 / 26 = 76.15
+        let result = estimate_paycheck_federal_withholdings(
+            Money::new(2000, 0),
+            FilingStatus::MarriedFilingJointly,
+            &TaxYear::default(),
+            PayFrequency::BiWeekly,
+        );
+        assert_eq!(result, Money::new(76, 15));
+    }
+
+    // TESTS FOR ESTIMATE_PERCENTAGE_METHOD_WITHHOLDING FUNCTION
+    fn percentage_method(filing_status: FilingStatus, w4: W4) -> Money {
+        estimate_percentage_method_withholding(
+            Money::new(2000, 0),
+            filing_status,
+            &w4,
+            &TaxYear::default(),
+            PayFrequency::BiWeekly,
+        )
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_percentage_method_matches_annualized_estimate_without_w4_adjustments() {
+        for filing_status in [
+            FilingStatus::Single,
+            FilingStatus::MarriedFilingJointly,
+            FilingStatus::HeadOfHousehold,
+        ] {
+            assert_eq!(
+                percentage_method(filing_status, W4::default()),
+                estimate_paycheck_federal_withholdings(
+                    Money::new(2000, 0),
+                    filing_status,
+                    &TaxYear::default(),
+                    PayFrequency::BiWeekly,
+                ),
+                "{:?}",
+                filing_status
+            );
+        }
+    }
+
+    #[test]
+    fn test_percentage_method_multiple_jobs() {
+        // (52,000 * 2 - 16,100) = 87,900 -> 5,800 + 37,500 * 22% = 14,050 / 2 = 7,025 / 26 = 270.19
+        let w4 = W4 {
+            multiple_jobs: true,
+            ..W4::default()
+        };
+        assert_eq!(
+            percentage_method(FilingStatus::Single, w4),
+            Money::new(270, 19)
+        );
+    }
+
+    #[test]
+    fn test_percentage_method_other_income_and_deductions() {
+        // 52,000 + 10,000 - 8,600 = 53,400 over 7,500 (16,100 - 8,600) -> 1,240 + 33,500 * 12% = 5,260 / 26 = 202.31
+        let other_income = W4 {
+            other_income: Money::new(10_000, 0),
+            ..W4::default()
+        };
+        assert_eq!(
+            percentage_method(FilingStatus::Single, other_income),
+            Money::new(202, 31)
+        );
+        // 52,000 - 5,200 - 16,100 = 30,700 -> 1,240 + 18,300 * 12% = 3,436 / 26 = 132.15
+        let deductions = W4 {
+            deductions: Money::new(5_200, 0),
+            ..W4::default()
+        };
+        assert_eq!(
+            percentage_method(FilingStatus::Single, deductions),
+            Money::new(132, 15)
+        );
+    }
+
+    #[test]
+    fn test_percentage_method_credits_and_extra_withholding() {
+        let w4 = W4 {
+            dependents_credit: Money::new(2_000, 0),
+            extra_withholding: Money::new(50, 0),
+            ..W4::default()
+        };
+        // 156.15 - 76.92 + 50.00
+        assert_eq!(
+            percentage_method(FilingStatus::Single, w4),
+            Money::new(129, 23)
+        );
+        // credits cannot reduce withholding below zero, but extra withholding still applies
+        let large_credit = W4 {
+            dependents_credit: Money::new(10_000, 0),
+            extra_withholding: Money::new(50, 0),
+            ..W4::default()
+        };
+        assert_eq!(
+            percentage_method(FilingStatus::Single, large_credit),
+            Money::new(50, 0)
+        );
+    }
+
+    #[test]
+    fn test_percentage_method_married_filing_separate_uses_single_schedule() {
+        let high_paycheck = Money::new(20_000, 0); // $520,000 annualized, above the MFS 37% threshold
+        let tax_year = TaxYear::default();
+        assert_eq!(
+            estimate_percentage_method_withholding(
+                high_paycheck,
+                FilingStatus::MarriedFilingSeparate,
+                &W4::default(),
+                &tax_year,
+                PayFrequency::BiWeekly,
+            ),
+            estimate_percentage_method_withholding(
+                high_paycheck,
+                FilingStatus::Single,
+                &W4::default(),
+                &tax_year,
+                PayFrequency::BiWeekly,
+            )
         );
     }
 
     // TESTS FOR ESTIMATE_SOCIAL_SECURITY_WITHHOLDING FUNCTION
     #[test]
     fn test_social_security_withholding() {
-        let gross_paycheck = 2000.0;
-        let result = estimate_social_security_withholding(gross_paycheck);
-        let expected = gross_paycheck * 0.062; // 6.2%
-        assert!(
-            (result - expected).abs() < 0.01,
-            "Expected {}, got {}",
-            expected,
-            result
-        );
+        let gross_paycheck = Money::new(2000, 0);
+        let result =
+            estimate_social_security_withholding(gross_paycheck, Money::ZERO, &TaxYear::default());
+        let expected = Money::new(124, 0); // 6.2%
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_social_security_withholding_wage_base() {
+        let tax_year = TaxYear::default();
+        let gross_paycheck = Money::new(2000, 0);
+        // $500 left under the $184,500 wage base
+        let crossing =
+            estimate_social_security_withholding(gross_paycheck, Money::new(184_000, 0), &tax_year);
+        assert_eq!(crossing, Money::new(31, 0));
+        // exactly at the wage base
+        let at_base =
+            estimate_social_security_withholding(gross_paycheck, Money::new(184_500, 0), &tax_year);
+        assert_eq!(at_base, Money::ZERO);
     }
     // TESTS FOR ESTIMATE_MEDICARE_WITHHOLDING FUNCTION
     #[test]
     fn test_medicare_withholding() {
-        let gross_paycheck = 2000.0;
-        let result = estimate_medicare_withholding(gross_paycheck);
-        let expected = gross_paycheck * 0.0145; // 1.45%
-        assert!(
-            (result - expected).abs() < 0.01,
-            "Expected {}, got {}",
-            expected,
-            result
-        );
+        let gross_paycheck = Money::new(2000, 0);
+        let result =
+            estimate_medicare_withholding(gross_paycheck, Money::ZERO, &TaxYear::default());
+        let expected = Money::new(29, 0); // 1.45%
+        assert_eq!(result.base, expected);
+        assert_eq!(result.additional, Money::ZERO);
+        assert_eq!(result.total(), expected);
+    }
+
+    #[test]
+    fn test_additional_medicare_withholding() {
+        let tax_year = TaxYear::default();
+        let gross_paycheck = Money::new(2000, 0);
+        // exactly at the threshold after this paycheck: nothing over $200,000
+        let at_threshold =
+            estimate_medicare_withholding(gross_paycheck, Money::new(198_000, 0), &tax_year);
+        assert_eq!(at_threshold.additional, Money::ZERO);
+        // crossing the threshold: $1,500 over $200,000
+        let crossing =
+            estimate_medicare_withholding(gross_paycheck, Money::new(199_500, 0), &tax_year);
+        assert_eq!(crossing.base, Money::new(29, 0));
+        assert_eq!(crossing.additional, Money::new(13, 50));
+        // already over the threshold: the whole paycheck
+        let over = estimate_medicare_withholding(gross_paycheck, Money::new(250_000, 0), &tax_year);
+        assert_eq!(over.additional, Money::new(18, 0));
+        assert_eq!(over.total(), Money::new(47, 0));
     }
 }
@@ -1 +1,506 @@
 // INTEGRATION TESTS
+
+use std::process::{Command, Output};
+
+/// Runs the `check-paycheck` binary with the given arguments
+fn check_paycheck(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_check-paycheck"))
+        .args(args)
+        .output()
+        .expect("failed to run check-paycheck")
+}
+
+#[test]
+fn test_calc_prints_results_without_prompts() {
+    let output = check_paycheck(&[
+        "calc",
+        "--rate",
+        "25",
+        "--hours",
+        "45",
+        "--pretax",
+        "medical=100",
+        "--pretax",
+        "dental=50",
+        "--pretax",
+        "vision=25",
+        "--pretax",
+        "traditional401k=200",
+        "--pretax",
+        "hsa=150",
+        "--posttax",
+        "roth401k=100",
+        "--posttax",
+        "life=30",
+        "--expense",
+        "housing=1500",
+        "--expense",
+        "energy=200",
+        "--expense",
+        "water=50",
+        "--expense",
+        "groceries=400",
+        "--expense",
+        "phone=80",
+        "--expense",
+        "internet=60",
+    ]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("1425.02"), "{stdout}");
+    assert!(
+        stdout.contains("Total Monthly Expenses: $2290.00"),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn test_calc_salary_and_filing_status() {
+    let output = check_paycheck(&[
+        "calc",
+        "--salary",
+        "52000",
+        "--exempt",
+        "--filing-status",
+        "married-jointly",
+        "--pay-frequency",
+        "monthly",
+    ]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("4333.33"), "{stdout}"); // 52,000 / 12
+}
+
+#[test]
+fn test_calc_w4_adjustments() {
+    let federal_income_tax = |w4_flags: &[&str]| {
+        let mut args = vec!["calc", "--rate", "25"];
+        args.extend_from_slice(w4_flags);
+        let output = check_paycheck(&args);
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        assert!(output.status.success(), "{stdout}");
+        stdout
+            .lines()
+            .find(|line| line.trim_start().starts_with("Federal Income Tax"))
+            .and_then(|line| line.split('$').nth(1))
+            .map(|amount| amount.trim().to_string())
+            .expect("federal income tax line")
+    };
+
+    assert_eq!(federal_income_tax(&[]), "156.15");
+    assert_eq!(
+        federal_income_tax(&["--w4-extra-withholding", "25"]),
+        "181.15"
+    );
+    // 156.15 - (2,000 / 26 = 76.92)
+    assert_eq!(
+        federal_income_tax(&["--w4-dependents-credit", "2000"]),
+        "79.23"
+    );
+    assert_eq!(federal_income_tax(&["--w4-multiple-jobs"]), "270.19");
+    // 52,000 + 10,000 - 10,000 leaves the annual wages unchanged
+    assert_eq!(
+        federal_income_tax(&["--w4-other-income", "10000", "--w4-deductions", "10000"]),
+        "156.15"
+    );
+}
+
+#[test]
+fn test_calc_rejects_invalid_flags() {
+    let unknown_kind = check_paycheck(&["calc", "--rate", "25", "--pretax", "pension=100"]);
+    assert!(!unknown_kind.status.success());
+    assert!(String::from_utf8_lossy(&unknown_kind.stderr).contains("unknown pre-tax deduction"));
+
+    let unknown_expense = check_paycheck(&["calc", "--rate", "25", "--expense", "childcare=800"]);
+    assert!(!unknown_expense.status.success());
+
+    let missing_rate = check_paycheck(&["calc", "--hours", "40"]);
+    assert!(!missing_rate.status.success());
+}
+
+#[test]
+fn test_run_scenario_file() {
+    let output = check_paycheck(&["run", "scenarios/example.toml"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("1425.02"), "{stdout}");
+
+    let missing = check_paycheck(&["run", "scenarios/missing.toml"]);
+    assert!(!missing.status.success());
+}
+
+#[test]
+fn test_output_formats() {
+    let json = check_paycheck(&["run", "scenarios/example.toml", "--output", "json"]);
+    assert!(json.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&json.stdout).unwrap();
+    assert_eq!(report["schema_version"], 1);
+    assert_eq!(report["paycheck"]["net_pay"], 1425.02);
+    assert_eq!(report["monthly"]["expenses"], 2290.0);
+
+    let csv = check_paycheck(&["run", "scenarios/example.toml", "--output", "csv"]);
+    let csv = String::from_utf8_lossy(&csv.stdout);
+    assert!(csv.starts_with("section,name,amount\n"), "{csv}");
+    assert!(csv.contains("paycheck,Net Pay,1425.02"), "{csv}");
+
+    let markdown = check_paycheck(&["run", "scenarios/example.toml", "--output", "markdown"]);
+    let markdown = String::from_utf8_lossy(&markdown.stdout);
+    assert!(markdown.contains("| Net Pay | 1425.02 |"), "{markdown}");
+
+    let unknown = check_paycheck(&["run", "scenarios/example.toml", "--output", "xml"]);
+    assert!(!unknown.status.success());
+}
+
+#[test]
+fn test_solve_hours() {
+    let output = check_paycheck(&[
+        "solve-hours",
+        "--rate",
+        "25",
+        "--surplus",
+        "500",
+        "--expense",
+        "housing=2500",
+        "--output",
+        "json",
+    ]);
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let hours = report["solution"]["hours_per_week"].as_f64().unwrap();
+    assert!(hours > 0.0 && hours < 168.0, "{hours}");
+    assert!(report["monthly"]["difference"].as_f64().unwrap() >= 500.0);
+
+    let unreachable =
+        check_paycheck(&["solve-hours", "--rate", "1", "--expense", "housing=100000"]);
+    assert!(!unreachable.status.success());
+    assert!(String::from_utf8_lossy(&unreachable.stderr).contains("168 hours per week"));
+}
+
+#[test]
+fn test_solve_rate() {
+    // the example scenario pays $25/hour for 45 hours a week, netting $1425.02
+    let output = check_paycheck(&[
+        "solve-rate",
+        "--hours",
+        "45",
+        "--pretax",
+        "medical=100",
+        "--pretax",
+        "dental=50",
+        "--pretax",
+        "vision=25",
+        "--pretax",
+        "traditional401k=200",
+        "--pretax",
+        "hsa=150",
+        "--posttax",
+        "roth401k=100",
+        "--posttax",
+        "life=30",
+        "--net-paycheck",
+        "1425.02",
+        "--output",
+        "json",
+    ]);
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["solution"]["hourly_rate"], 25.0);
+    assert_eq!(report["paycheck"]["net_pay"], 1425.02);
+
+    let no_hours = check_paycheck(&["solve-rate", "--hours", "0", "--surplus", "100"]);
+    assert!(!no_hours.status.success());
+    assert!(String::from_utf8_lossy(&no_hours.stderr).contains("$100.00 left over each month"));
+}
+
+#[test]
+fn test_compare_files_and_flags() {
+    let output = check_paycheck(&[
+        "compare",
+        "scenarios/example.toml",
+        "--scenario",
+        "--rate 30 --hours 40",
+        "--output",
+        "csv",
+    ]);
+    let csv = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{csv}");
+    assert!(
+        csv.starts_with(",example,scenario-1,scenario-1 vs example\n"),
+        "{csv}"
+    );
+    assert!(csv.contains("Net Pay,1425.02,"), "{csv}");
+    assert!(csv.contains("Gross Pay,2375.00,2400.00,+25.00"), "{csv}");
+
+    let one_scenario = check_paycheck(&["compare", "scenarios/example.toml"]);
+    assert!(!one_scenario.status.success());
+
+    let unknown_baseline = check_paycheck(&[
+        "compare",
+        "scenarios/example.toml",
+        "--scenario",
+        "--rate 30",
+        "--baseline",
+        "offer",
+    ]);
+    assert!(!unknown_baseline.status.success());
+}
+
+#[test]
+fn test_calc_custom_expenses() {
+    let output = check_paycheck(&[
+        "calc",
+        "--rate",
+        "25",
+        "--expense",
+        "housing=1500",
+        "--expense",
+        "custom:Childcare:Family=800",
+        "--expense",
+        "custom:Student Loans=250.50",
+    ]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(
+        stdout.contains("Total Monthly Expenses: $2550.50"),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn test_compare_scenario_flags_with_quoted_values() {
+    let output = check_paycheck(&[
+        "compare",
+        "--scenario",
+        "--rate 25 --expense housing=1500",
+        "--scenario",
+        "--rate 25 --expense housing=1500 --expense 'custom:Student Loan=300' --posttax \"custom:Union Dues=40\"",
+        "--output",
+        "json",
+    ]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    let comparison: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let scenario = &comparison["scenarios"][1];
+    assert_eq!(scenario["monthly_expenses"], 1800.0);
+    assert_eq!(scenario["posttax_deductions"], 40.0);
+
+    let unclosed = check_paycheck(&[
+        "compare",
+        "--scenario",
+        "--rate 25",
+        "--scenario",
+        "--rate 25 --expense 'custom:Student Loan=300",
+    ]);
+    assert!(!unclosed.status.success());
+    assert!(String::from_utf8_lossy(&unclosed.stderr).contains("unclosed ' quote"));
+}
+
+#[test]
+fn test_calc_expense_frequencies() {
+    let output = check_paycheck(&[
+        "calc",
+        "--rate",
+        "25",
+        "--expense",
+        "housing=1500",
+        "--expense",
+        "groceries=120/weekly",
+        "--expense",
+        "custom:Registration=180/annual",
+    ]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    // 1,500 + 120 * 52 / 12 + 180 / 12
+    assert!(
+        stdout.contains("Total Monthly Expenses: $2035.00"),
+        "{stdout}"
+    );
+
+    let unknown = check_paycheck(&["calc", "--rate", "25", "--expense", "groceries=120/daily"]);
+    assert!(!unknown.status.success());
+}
+
+#[test]
+fn test_calc_custom_deductions() {
+    let output = check_paycheck(&[
+        "calc",
+        "--rate",
+        "25",
+        "--hours",
+        "40",
+        "--pretax",
+        "custom:457(b):federal-only=300",
+        "--posttax",
+        "custom:Union Dues=40",
+        "--output",
+        "json",
+    ]);
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["paycheck"]["taxable_wages"], 1700.0);
+    assert_eq!(report["paycheck"]["fica_wages"], 2000.0);
+    assert_eq!(
+        report["paycheck"]["posttax_deductions"][0]["name"],
+        "Union Dues"
+    );
+
+    let unknown = check_paycheck(&["calc", "--rate", "25", "--pretax", "custom:457(b):roth=300"]);
+    assert!(!unknown.status.success());
+}
+
+#[test]
+fn test_calc_percentage_deductions() {
+    let output = check_paycheck(&[
+        "calc",
+        "--rate",
+        "25",
+        "--hours",
+        "45",
+        "--pretax",
+        "traditional401k=6%",
+        "--posttax",
+        "roth401k=2%regular",
+        "--output",
+        "json",
+    ]);
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["paycheck"]["pretax_deductions"][0]["amount"], 142.5);
+    assert_eq!(report["paycheck"]["posttax_deductions"][0]["amount"], 40.0);
+
+    let invalid = check_paycheck(&["calc", "--rate", "25", "--pretax", "401k=150%"]);
+    assert!(!invalid.status.success());
+}
+
+#[test]
+fn test_calc_contribution_limits() {
+    let output = check_paycheck(&[
+        "calc",
+        "--rate",
+        "50",
+        "--pretax",
+        "401k=1000",
+        "--output",
+        "json",
+    ]);
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let limit = &report["contribution_limits"][0];
+    assert_eq!(limit["plan"], "elective-deferral");
+    assert_eq!(limit["pay_period"], 25);
+    assert_eq!(limit["not_contributed"], 1500.0);
+
+    // the age 60-63 catch-up raises the limit to $35,750
+    let catch_up = check_paycheck(&[
+        "calc",
+        "--rate",
+        "50",
+        "--pretax",
+        "401k=1000",
+        "--age",
+        "61",
+        "--output",
+        "json",
+    ]);
+    assert!(catch_up.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&catch_up.stdout).unwrap();
+    assert_eq!(report["contribution_limits"], serde_json::json!([]));
+
+    let text = check_paycheck(&["calc", "--rate", "50", "--pretax", "hsa=200"]);
+    let stdout = String::from_utf8_lossy(&text.stdout);
+    assert!(
+        stdout.contains("HSA reach the $4400.00 limit in pay period 22"),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn test_calc_total_compensation() {
+    let output = check_paycheck(&[
+        "calc",
+        "--rate",
+        "25",
+        "--hours",
+        "45",
+        "--pretax",
+        "401k=2%",
+        "--employer-match",
+        "100%@3%,50%@5%",
+        "--employer-hsa",
+        "500",
+        "--employer-premiums",
+        "250",
+        "--output",
+        "json",
+    ]);
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let compensation = &report["total_compensation"];
+    assert_eq!(compensation["employer_match"], 1235.0);
+    assert_eq!(compensation["total"], 69985.0);
+    assert_eq!(compensation["missed_match"]["missed"], 1235.0);
+
+    let text = check_paycheck(&[
+        "calc",
+        "--rate",
+        "25",
+        "--pretax",
+        "401k=2%",
+        "--employer-match",
+        "50%@6%",
+    ]);
+    let stdout = String::from_utf8_lossy(&text.stdout);
+    assert!(
+        stdout.contains("Missed Match: contribute at least $120.00 per paycheck (6% of pay)"),
+        "{stdout}"
+    );
+
+    let invalid = check_paycheck(&["calc", "--rate", "25", "--employer-match", "100%"]);
+    assert!(!invalid.status.success());
+    let duplicate = check_paycheck(&["calc", "--rate", "25", "--employer-match", "100%@4%,50%@4%"]);
+    assert!(!duplicate.status.success());
+}
+
+#[test]
+fn test_calc_garnishment_limits() {
+    let output = check_paycheck(&[
+        "calc",
+        "--rate",
+        "25",
+        "--posttax",
+        "garnishment=600",
+        "--posttax",
+        "childsupport=300",
+        "--output",
+        "json",
+    ]);
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let garnishments = &report["paycheck"]["garnishments"];
+    assert_eq!(garnishments[0]["kind"], "child-support");
+    assert_eq!(garnishments[0]["withheld"], 300.0);
+    assert_eq!(garnishments[1]["kind"], "creditor");
+    assert_eq!(garnishments[1]["withheld"], 122.71);
+    assert_eq!(garnishments[1]["arrears"], 477.29);
+
+    let text = check_paycheck(&[
+        "calc",
+        "--rate",
+        "25",
+        "--posttax",
+        "support=1500",
+        "--supports-second-family",
+        "--support-arrears-over-12-weeks",
+    ]);
+    let stdout = String::from_utf8_lossy(&text.stdout);
+    assert!(text.status.success());
+    // 55% of $1,690.85 disposable earnings
+    assert!(stdout.contains("$      929.97"), "{stdout}");
+    assert!(stdout.contains("Garnishment Arrears"), "{stdout}");
+    assert!(stdout.contains("$      570.03"), "{stdout}");
+}